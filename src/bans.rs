@@ -0,0 +1,110 @@
+use crate::{
+    error::GroupError,
+    models::{bans::GroupBan, permissions::Permission},
+    Contract, ContractExt,
+};
+
+use near_sdk::{env, near, AccountId};
+
+#[near]
+impl Contract {
+    /// Bans an account from a group, removing it first when it is a member.
+    #[handle_result]
+    pub fn ban_group_member(
+        &mut self,
+        group_id: u32,
+        account_id: AccountId,
+        reason: Option<String>,
+        expires_on: Option<u64>,
+    ) -> Result<(), GroupError> {
+        let caller = env::predecessor_account_id();
+        let group = self.get_group_for_update(group_id, &caller, Permission::ModerateContent)?;
+
+        if !group.role_of(&caller).outranks(&group.role_of(&account_id)) {
+            return Err(GroupError::Unauthorized);
+        }
+
+        if group.is_member(account_id.clone()) {
+            self.release_member(group_id, &account_id)?;
+        }
+        if let Some(request) = self.take_join_request(group_id, &account_id) {
+            self.refund_deposit(&account_id, request.deposit.0);
+        }
+        if let Some(entry) = self.take_waitlist_entry(group_id, &account_id) {
+            self.refund_deposit(&account_id, entry.deposit.0);
+        }
+
+        let bans = self.group_bans.entry(group_id).or_default();
+        bans.retain(|ban| ban.account_id != account_id);
+        bans.push(GroupBan::new(account_id.clone(), reason, expires_on));
+
+        env::log_str(
+            format!(
+                "Account {} banned from group {} by {}",
+                account_id, group_id, caller
+            )
+            .as_str(),
+        );
+        Ok(())
+    }
+
+    #[handle_result]
+    pub fn unban_group_member(
+        &mut self,
+        group_id: u32,
+        account_id: AccountId,
+    ) -> Result<(), GroupError> {
+        let caller = env::predecessor_account_id();
+        self.get_group_for_update(group_id, &caller, Permission::ModerateContent)?;
+
+        let bans = self
+            .group_bans
+            .get_mut(&group_id)
+            .ok_or(GroupError::NotBanned)?;
+        let position = bans
+            .iter()
+            .position(|ban| ban.account_id == account_id)
+            .ok_or(GroupError::NotBanned)?;
+        bans.remove(position);
+
+        if bans.is_empty() {
+            self.group_bans.remove(&group_id);
+        }
+        env::log_str(
+            format!(
+                "Account {} unbanned from group {} by {}",
+                account_id, group_id, caller
+            )
+            .as_str(),
+        );
+        Ok(())
+    }
+
+    /// Active bans of a group, expired ones are left out.
+    pub fn get_group_bans(&self, group_id: u32, index: u32, limit: u32) -> Vec<GroupBan> {
+        self.group_bans
+            .get(&group_id)
+            .map(|bans| {
+                bans.iter()
+                    .filter(|ban| ban.is_active())
+                    .skip(index as _)
+                    .take(limit as _)
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn is_user_banned_from_group(&self, account_id: AccountId, group_id: u32) -> bool {
+        self.is_banned(group_id, &account_id)
+    }
+}
+
+impl Contract {
+    pub(crate) fn is_banned(&self, group_id: u32, account_id: &AccountId) -> bool {
+        self.group_bans.get(&group_id).is_some_and(|bans| {
+            bans.iter()
+                .any(|ban| ban.account_id == *account_id && ban.is_active())
+        })
+    }
+}
@@ -8,6 +8,52 @@ pub enum GroupError {
     AlreadyMember,
     NotMember,
     UserAlreadyInGroup,
+    Unauthorized,
+    InvalidRole,
+    TransferAlreadyPending,
+    NoPendingTransfer,
+    OwnerCannotLeave,
+    GroupDeleted,
+    GroupNotDeleted,
+    GracePeriodExpired,
+    JoinRequestRequired,
+    InviteRequired,
+    JoinRequestsNotAccepted,
+    JoinRequestAlreadyPending,
+    JoinRequestNotFound,
+    InviteNotFound,
+    InviteRevoked,
+    InviteExpired,
+    InviteUsedUp,
+    Banned,
+    NotBanned,
+    InvalidGroupName,
+    GroupNameTaken,
+    GroupFull,
+    AlreadyWaitlisted,
+    NotWaitlisted,
+    ParentMembershipRequired,
+    InvalidGeohash,
+    InvalidMatrixUserId,
+    MatrixUserIdTaken,
+    MatrixUserIdClaimNotFound,
+    InsufficientTokenBalance,
+    GateCheckFailed,
+    RequiredNftMissing,
+    InsufficientDeposit,
+    InsufficientTreasuryBalance,
+    MembershipDoesNotExpire,
+    InvalidGovernanceSettings,
+    ProposalNotFound,
+    VotingClosed,
+    VotingStillOpen,
+    AlreadyVoted,
+    NoVotingWeight,
+    InvalidPoll,
+    PollNotFound,
+    PollClosed,
+    InvalidPollVote,
+    JoinedAfterProposal,
 }
 
 impl AsRef<str> for GroupError {
@@ -18,6 +64,76 @@ impl AsRef<str> for GroupError {
             GroupError::AlreadyMember => "Already a member of this group",
             GroupError::NotMember => "Not a member of this group",
             GroupError::UserAlreadyInGroup => "User already in group",
+            GroupError::Unauthorized => "Not authorized to perform this action in this group",
+            GroupError::InvalidRole => "This role can not be assigned or changed",
+            GroupError::TransferAlreadyPending => "An ownership transfer is already pending",
+            GroupError::NoPendingTransfer => "No pending ownership transfer for this group",
+            GroupError::GroupDeleted => "Group has been deleted",
+            GroupError::GroupNotDeleted => "Group has not been deleted",
+            GroupError::GracePeriodExpired => "Grace period to restore the group has expired",
+            GroupError::JoinRequestRequired => {
+                "This group only admits members through a join request"
+            }
+            GroupError::InviteRequired => "This group only admits members through an invite",
+            GroupError::JoinRequestsNotAccepted => "This group does not accept join requests",
+            GroupError::JoinRequestAlreadyPending => {
+                "A join request for this group is already pending"
+            }
+            GroupError::JoinRequestNotFound => "Join request not found",
+            GroupError::InviteNotFound => "Invite not found",
+            GroupError::InviteRevoked => "Invite has been revoked",
+            GroupError::InviteExpired => "Invite has expired",
+            GroupError::InviteUsedUp => "Invite has reached its maximum number of uses",
+            GroupError::Banned => "Account is banned from this group",
+            GroupError::NotBanned => "Account is not banned from this group",
+            GroupError::InvalidGroupName => "Group name must contain a letter or digit",
+            GroupError::GroupNameTaken => "A group with this name already exists",
+            GroupError::GroupFull => "Group has reached its maximum number of members",
+            GroupError::AlreadyWaitlisted => "Already on the waitlist of this group",
+            GroupError::NotWaitlisted => "Not on the waitlist of this group",
+            GroupError::InvalidGeohash => "Geohash must only use geohash base32 characters",
+            GroupError::InvalidMatrixUserId => "Matrix user id must look like @user:server",
+            GroupError::MatrixUserIdTaken => "Matrix user id is linked to another account",
+            GroupError::MatrixUserIdClaimNotFound => {
+                "No pending claim of this Matrix user id for the account"
+            }
+            GroupError::InsufficientTokenBalance => {
+                "Token balance is below the minimum required by this group"
+            }
+            GroupError::GateCheckFailed => "Could not verify the tokens required by this group",
+            GroupError::RequiredNftMissing => {
+                "Account holds no token of the collection required by this group"
+            }
+            GroupError::InsufficientDeposit => "Attached deposit does not cover the join fee",
+            GroupError::InsufficientTreasuryBalance => {
+                "Amount must be positive and covered by the group's treasury"
+            }
+            GroupError::MembershipDoesNotExpire => "Memberships of this group do not expire",
+            GroupError::InvalidGovernanceSettings => {
+                "Quorum must be at most 100%, threshold below 100% and the voting period positive"
+            }
+            GroupError::ProposalNotFound => "Proposal not found",
+            GroupError::VotingClosed => "Proposal is no longer open for votes",
+            GroupError::VotingStillOpen => "Proposal is still open for votes",
+            GroupError::AlreadyVoted => "Already voted on this proposal",
+            GroupError::NoVotingWeight => "Only members with voting weight can take part",
+            GroupError::InvalidPoll => {
+                "A poll needs a question, 2 to 20 options and a deadline in the future"
+            }
+            GroupError::PollNotFound => "Poll not found",
+            GroupError::PollClosed => "Poll has passed its deadline",
+            GroupError::InvalidPollVote => {
+                "Votes must pick existing options, only one unless the poll allows several"
+            }
+            GroupError::JoinedAfterProposal => {
+                "Only members who joined before the proposal was created can vote"
+            }
+            GroupError::ParentMembershipRequired => {
+                "Only members of the parent group can join this group"
+            }
+            GroupError::OwnerCannotLeave => {
+                "Owner can not leave the group before transferring ownership"
+            }
         }
     }
 }
@@ -0,0 +1,185 @@
+use crate::{
+    error::GroupError,
+    models::{
+        application_role::ApplicationRole,
+        gates::{MembershipGate, NftToken},
+        treasury::TreasuryTransferKind,
+    },
+    Contract, ContractExt,
+};
+
+use near_sdk::{env, ext_contract, json_types::U128, near, AccountId, Gas, Promise, PromiseError};
+
+const GAS_FOR_BALANCE_CHECK: Gas = Gas::from_tgas(10);
+const GAS_FOR_NFT_CHECK: Gas = Gas::from_tgas(10);
+const GAS_FOR_GATE_CALLBACK: Gas = Gas::from_tgas(20);
+
+#[ext_contract(ext_fungible_token)]
+#[allow(dead_code)]
+trait FungibleToken {
+    fn ft_balance_of(&self, account_id: AccountId) -> U128;
+}
+
+#[ext_contract(ext_non_fungible_token)]
+#[allow(dead_code)]
+trait NonFungibleToken {
+    fn nft_tokens_for_owner(
+        &self,
+        account_id: AccountId,
+        from_index: Option<U128>,
+        limit: Option<u64>,
+    ) -> Vec<NftToken>;
+}
+
+#[near]
+impl Contract {
+    /// Requires accounts to hold a token before joining, `None` opens the group again.
+    /// Current members keep their membership when the gate changes.
+    #[handle_result]
+    pub fn set_group_gate(
+        &mut self,
+        group_id: u32,
+        gate: Option<MembershipGate>,
+    ) -> Result<(), GroupError> {
+        let account_id = env::predecessor_account_id();
+        let group = self.get_owned_group_for_update(group_id, &account_id)?;
+        group.gate = gate;
+        group.updated_on = env::block_timestamp();
+        self.index_group_listing(group_id);
+        env::log_str(format!("Membership gate of group {} updated", group_id).as_str());
+        Ok(())
+    }
+
+    /// Finishes a gated `join_group` once the token balance is known. The group
+    /// may have filled up in the meantime, the account is waitlisted then.
+    #[private]
+    #[handle_result]
+    pub fn on_token_gate_checked(
+        &mut self,
+        group_id: u32,
+        account_id: AccountId,
+        min_balance: U128,
+        deposit: U128,
+        #[callback_result] balance: Result<U128, PromiseError>,
+    ) -> Result<(), GroupError> {
+        let checked = match balance {
+            Ok(balance) if balance.0 < min_balance.0 => Err(GroupError::InsufficientTokenBalance),
+            Ok(_) => Ok(()),
+            Err(_) => Err(GroupError::GateCheckFailed),
+        };
+        self.finish_gated_join(group_id, &account_id, deposit.0, checked)
+    }
+
+    /// Finishes a `join_group` gated on an NFT collection once the caller's tokens are known.
+    #[private]
+    #[handle_result]
+    pub fn on_nft_gate_checked(
+        &mut self,
+        group_id: u32,
+        account_id: AccountId,
+        deposit: U128,
+        #[callback_result] tokens: Result<Vec<NftToken>, PromiseError>,
+    ) -> Result<(), GroupError> {
+        let checked = match tokens {
+            Ok(tokens) if tokens.iter().any(|token| token.owner_id == account_id) => Ok(()),
+            Ok(_) => Err(GroupError::RequiredNftMissing),
+            Err(_) => Err(GroupError::GateCheckFailed),
+        };
+        self.finish_gated_join(group_id, &account_id, deposit.0, checked)
+    }
+}
+
+impl Contract {
+    /// Starts the cross-contract check of a group's gate for an account.
+    pub(crate) fn check_gate(
+        &self,
+        group_id: u32,
+        account_id: AccountId,
+        gate: MembershipGate,
+        deposit: u128,
+    ) -> Promise {
+        match gate {
+            MembershipGate::FungibleToken {
+                contract_id,
+                min_balance,
+            } => ext_fungible_token::ext(contract_id)
+                .with_static_gas(GAS_FOR_BALANCE_CHECK)
+                .ft_balance_of(account_id.clone())
+                .then(
+                    Self::ext(env::current_account_id())
+                        .with_static_gas(GAS_FOR_GATE_CALLBACK)
+                        .on_token_gate_checked(group_id, account_id, min_balance, U128(deposit)),
+                ),
+            // A single token is enough, so only the first one is requested
+            MembershipGate::NonFungibleToken { contract_id } => {
+                ext_non_fungible_token::ext(contract_id)
+                    .with_static_gas(GAS_FOR_NFT_CHECK)
+                    .nft_tokens_for_owner(account_id.clone(), None, Some(1))
+                    .then(
+                        Self::ext(env::current_account_id())
+                            .with_static_gas(GAS_FOR_GATE_CALLBACK)
+                            .on_nft_gate_checked(group_id, account_id, U128(deposit)),
+                    )
+            }
+        }
+    }
+
+    /// Admits the account or waitlists it when the group is full. The join fee is
+    /// taken from the deposit, held on the waitlist if needed, and the rest refunded.
+    pub(crate) fn admit_or_waitlist(
+        &mut self,
+        group_id: u32,
+        account_id: &AccountId,
+        deposit: u128,
+    ) -> Result<(), GroupError> {
+        let group = self
+            .groups
+            .get(&group_id)
+            .ok_or(GroupError::GroupNotFound)?;
+
+        let join_fee = group.join_fee.map_or(0, |join_fee| join_fee.0);
+        if deposit < join_fee {
+            return Err(GroupError::InsufficientDeposit);
+        }
+        if group.is_full() {
+            self.join_waitlist(group_id, account_id, join_fee)?;
+        } else {
+            self.admit_member(group_id, account_id, ApplicationRole::Member, None)?;
+            self.credit_treasury(
+                group_id,
+                account_id,
+                join_fee,
+                TreasuryTransferKind::JoinFee,
+            );
+        }
+        self.refund_deposit(account_id, deposit - join_fee);
+        Ok(())
+    }
+
+    /// A callback that panics would also revert the refund of the deposit, so a
+    /// failed join with a deposit is logged and refunded instead.
+    fn finish_gated_join(
+        &mut self,
+        group_id: u32,
+        account_id: &AccountId,
+        deposit: u128,
+        checked: Result<(), GroupError>,
+    ) -> Result<(), GroupError> {
+        match checked.and_then(|()| self.admit_or_waitlist(group_id, account_id, deposit)) {
+            Err(error) if deposit > 0 => {
+                env::log_str(
+                    format!(
+                        "Join of {} to group {} failed: {}",
+                        account_id,
+                        group_id,
+                        error.as_ref()
+                    )
+                    .as_str(),
+                );
+                self.refund_deposit(account_id, deposit);
+                Ok(())
+            }
+            result => result,
+        }
+    }
+}
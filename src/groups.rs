@@ -2,13 +2,19 @@ use crate::{
     error::GroupError,
     models::{
         application_role::ApplicationRole,
-        groups::{GroupResponse, PostGroup, UpdateGroup},
+        groups::{
+            group_slug, normalize_group_name, GroupResponse, GroupVisibility, GroupWithMembers,
+            JoinPolicy, PostGroup, UpdateGroup,
+        },
+        members::{MatrixMember, MemberFilter, MemberResponse, MemberSort, SuccessionPolicy},
+        permissions::Permission,
         rewards::Rewards,
+        sorting::SortOrder,
     },
     Contract, ContractExt,
 };
 
-use near_sdk::{env, near, AccountId};
+use near_sdk::{env, near, AccountId, PromiseOrValue};
 use std::collections::HashMap;
 
 #[near]
@@ -26,154 +32,838 @@ impl Contract {
         let group_id = self.group_id_counter;
         profile.joined_groups.push(group_id);
 
-        self.groups.insert(group_id, post_group.into());
+        self.check_group_name_available(&post_group.name, group_id)?;
+        if post_group
+            .location
+            .as_ref()
+            .is_some_and(|location| !location.is_valid())
+        {
+            return Err(GroupError::InvalidGeohash);
+        }
+        if let Some(parent_id) = post_group.parent_id {
+            self.get_group_for_update(parent_id, &account_id, Permission::CreateSubgroups)?;
+            self.link_subgroup(parent_id, group_id);
+        }
+        let group = GroupWithMembers::new(group_id, post_group);
+        self.index_group_name(group_id, &group.name);
+        self.index_group_text(group_id, &group.name, &group.description);
+        self.index_group_tags(group_id, &group.tags);
+        self.index_group_location(group_id, &group.location);
+        self.groups.insert(group_id, group);
+        self.index_group_listing(group_id);
         self.group_id_counter += 1;
         env::log_str((format!("Group added with id {}", group_id)).as_str());
         Ok(group_id)
     }
 
-    pub fn edit_group(&mut self, id: u32, update_group: UpdateGroup) -> Option<()> {
-        let current_group = self.groups.get(&id)?;
-        //instead of cloning the whole current group here, only clone internally what is needed.
-        let updated_group = current_group.update(update_group);
-        self.groups.insert(id, updated_group);
-        env::log_str(format!("Group {} updated", id).as_str());
-        Some(())
+    #[handle_result]
+    pub fn edit_group(&mut self, id: u32, update_group: UpdateGroup) -> Result<(), GroupError> {
+        let account_id = env::predecessor_account_id();
+        let current_group = self.get_group_for_update(id, &account_id, Permission::EditMetadata)?;
+
+        if update_group.matrix_space_id.is_some() && current_group.owner != account_id {
+            return Err(GroupError::Unauthorized);
+        }
+        self.apply_group_update(id, update_group)?;
+        env::log_str(format!("Group {} updated by {}", id, account_id).as_str());
+        Ok(())
     }
 
-    pub fn get_group(&self, id: u32) -> Option<GroupResponse> {
-        let group = self.groups.get(&id)?;
-        Some(GroupResponse::new(id, group.clone()))
+    pub fn get_group(&self, id: u32, include_deleted: Option<bool>) -> Option<GroupResponse> {
+        self.visible_group(id, include_deleted)
+            .map(|group| GroupResponse::new(id, group))
     }
 
-    pub fn get_group_by_name(&self, name: String) -> Option<GroupResponse> {
-        self.groups
-            .iter()
-            .find(|(_, group)| group.name == name)
-            .map(|(id, group)| GroupResponse::new(*id, group.clone()))
+    pub fn get_group_by_name(
+        &self,
+        name: String,
+        include_deleted: Option<bool>,
+    ) -> Option<GroupResponse> {
+        let id = self.group_names.get(&normalize_group_name(&name))?;
+        self.get_group(*id, include_deleted)
     }
 
-    pub fn get_groups(&self, index: u32, limit: u32) -> Vec<GroupResponse> {
+    pub fn get_group_by_slug(
+        &self,
+        slug: String,
+        include_deleted: Option<bool>,
+    ) -> Option<GroupResponse> {
+        let id = self.group_slugs.get(&slug.to_lowercase())?;
+        self.get_group(*id, include_deleted)
+    }
+
+    pub fn get_groups(
+        &self,
+        index: u32,
+        limit: u32,
+        include_deleted: Option<bool>,
+    ) -> Vec<GroupResponse> {
+        let include_deleted = include_deleted.unwrap_or(false);
         let mut groups: Vec<GroupResponse> = vec![];
-        for (id, group) in self.groups.iter().skip(index as _).take(limit as _) {
-            groups.push(GroupResponse::new(*id, group.clone()));
+        for (id, group) in self
+            .groups
+            .iter()
+            .filter(|(_, group)| include_deleted || !group.is_deleted)
+            .filter(|(_, group)| group.visibility == GroupVisibility::Public)
+            .skip(index as _)
+            .take(limit as _)
+        {
+            groups.push(GroupResponse::new(*id, group));
         }
         groups
     }
 
-    pub fn get_groups_by_id(&self, ids: Vec<u32>) -> Vec<GroupResponse> {
+    pub fn get_groups_by_id(
+        &self,
+        ids: Vec<u32>,
+        include_deleted: Option<bool>,
+    ) -> Vec<GroupResponse> {
+        let include_deleted = include_deleted.unwrap_or(false);
         let mut groups: Vec<GroupResponse> = vec![];
         for id in &ids {
             if let Some(_group) = self.groups.get(id) {
-                groups.push(GroupResponse::new(*id, _group.clone()));
+                if include_deleted || !_group.is_deleted {
+                    groups.push(GroupResponse::new(*id, _group));
+                }
             }
         }
         groups
     }
 
-    //Don't return Okay for Errors.
     #[handle_result]
-    pub fn join_group(&mut self, group_id: u32) -> Result<(), GroupError> {
+    pub fn delete_group(&mut self, group_id: u32) -> Result<(), GroupError> {
         let account_id = env::predecessor_account_id();
+        let group = self.get_group_for_update(group_id, &account_id, Permission::DeleteGroup)?;
+        group.delete();
+        self.ownership_transfers.remove(&group_id);
+        self.index_group_listing(group_id);
+        env::log_str(format!("Group {} deleted by {}", group_id, account_id).as_str());
+        Ok(())
+    }
 
-        // Update Profile
-        let profile = self
-            .profiles
-            .get_mut(&account_id)
-            .ok_or(GroupError::ProfileNotFound)?;
+    #[handle_result]
+    pub fn restore_group(&mut self, group_id: u32) -> Result<(), GroupError> {
+        let account_id = env::predecessor_account_id();
+        let group = self
+            .groups
+            .get_mut(&group_id)
+            .ok_or(GroupError::GroupNotFound)?;
 
-        if profile.joined_groups.contains(&group_id) {
-            return Err(GroupError::AlreadyMember);
+        if !group.has_permission(&account_id, Permission::DeleteGroup) {
+            return Err(GroupError::Unauthorized);
         }
-        profile.joined_groups.push(group_id);
+        if !group.is_deleted {
+            return Err(GroupError::GroupNotDeleted);
+        }
+        if !group.is_restorable() {
+            return Err(GroupError::GracePeriodExpired);
+        }
+        group.restore();
+        self.index_group_listing(group_id);
+        env::log_str(format!("Group {} restored by {}", group_id, account_id).as_str());
+        Ok(())
+    }
 
-        // Update Group
+    /// Permanently removes a deleted group and frees its storage.
+    #[private]
+    #[handle_result]
+    pub fn purge_group(&mut self, group_id: u32) -> Result<(), GroupError> {
         let group = self
             .groups
             .get_mut(&group_id)
             .ok_or(GroupError::GroupNotFound)?;
 
-        if group.members.members.contains_key(&account_id) {
-            return Err(GroupError::UserAlreadyInGroup);
+        if !group.is_deleted {
+            return Err(GroupError::GroupNotDeleted);
         }
-        group
-            .members
-            .members
-            .insert(account_id.clone(), ApplicationRole::Member);
 
-        match self.rewards.get_mut(&account_id) {
-            Some(reward) => {
-                reward.group_join(group_id);
+        let members = group.get_members();
+        for account_id in &members {
+            if let Some(profile) = self.profiles.get_mut(account_id) {
+                profile.joined_groups.retain(|&x| x != group_id);
             }
-            None => {
-                let mut new_reward = Rewards::default();
-                new_reward.group_join(group_id);
-                self.rewards.insert(account_id.clone(), new_reward);
+        }
+        // The members live in their own collection, clear it to free its storage
+        group.members.clear();
+        let name = group.name.clone();
+        let description = group.description.clone();
+        let tags = group.tags.clone();
+        let parent_id = group.parent_id;
+        let location = group.location.clone();
+        let owner = group.owner.clone();
+        self.unlink_subgroup(group_id, parent_id);
+        self.unindex_group_location(group_id, &location);
+        self.unindex_group_name(group_id, &name);
+        self.unindex_group_text(group_id, &name, &description);
+        self.unindex_group_tags(group_id, &tags);
+        self.unindex_group_listing(group_id);
+        for account_id in &members {
+            self.revoke_membership_token(group_id, account_id);
+        }
+        self.groups.remove(&group_id);
+        self.ownership_transfers.remove(&group_id);
+        for request in self.join_requests.remove(&group_id).unwrap_or_default() {
+            self.refund_deposit(&request.account_id, request.deposit.0);
+        }
+        for entry in self.group_waitlists.remove(&group_id).unwrap_or_default() {
+            self.refund_deposit(&entry.account_id, entry.deposit.0);
+        }
+        self.close_treasury(group_id, &owner);
+        self.group_bans.remove(&group_id);
+        let invite_ids: Vec<String> = self
+            .group_invites
+            .iter()
+            .filter(|(_, invite)| invite.group_id == group_id)
+            .map(|(invite_id, _)| invite_id.clone())
+            .collect();
+        for invite_id in invite_ids {
+            self.group_invites.remove(&invite_id);
+        }
+        for proposal_id in self.group_proposals.remove(&group_id).unwrap_or_default() {
+            if let Some(mut proposal) = self.proposals.remove(&proposal_id) {
+                proposal.votes.clear();
             }
-        };
-
+        }
+        for poll_id in self.group_polls.remove(&group_id).unwrap_or_default() {
+            if let Some(mut poll) = self.polls.remove(&poll_id) {
+                poll.votes.clear();
+            }
+        }
+        env::log_str(format!("Group {} purged", group_id).as_str());
         Ok(())
     }
 
+    //Don't return Okay for Errors.
+    /// Gated groups check the caller's tokens first and finish the join in a callback.
+    /// The attached deposit must cover the group's join fee, the rest is refunded.
+    #[payable]
     #[handle_result]
-    pub fn leave_group(&mut self, group_id: u32) -> Result<(), GroupError> {
+    pub fn join_group(&mut self, group_id: u32) -> Result<PromiseOrValue<()>, GroupError> {
         let account_id = env::predecessor_account_id();
+        let deposit = env::attached_deposit().as_yoctonear();
+        let group = self
+            .groups
+            .get(&group_id)
+            .ok_or(GroupError::GroupNotFound)?;
 
-        // Update Profile
-        // Update Profile
-        let profile = self
-            .profiles
-            .get_mut(&account_id)
-            .ok_or(GroupError::ProfileNotFound)?;
-
-        if !profile.joined_groups.contains(&group_id) {
-            return Err(GroupError::NotMember);
+        match group.join_policy {
+            JoinPolicy::Open => {}
+            JoinPolicy::RequestToJoin => return Err(GroupError::JoinRequestRequired),
+            JoinPolicy::InviteOnly => return Err(GroupError::InviteRequired),
+        }
+        if group.join_fee.is_some_and(|join_fee| deposit < join_fee.0) {
+            return Err(GroupError::InsufficientDeposit);
         }
 
-        profile.joined_groups.retain(|&x| x != group_id);
+        match group.gate.clone() {
+            Some(gate) => {
+                self.check_can_admit(group_id, &account_id)?;
+                Ok(self.check_gate(group_id, account_id, gate, deposit).into())
+            }
+            None => self
+                .admit_or_waitlist(group_id, &account_id, deposit)
+                .map(PromiseOrValue::Value),
+        }
+    }
 
-        // Update Group
+    #[handle_result]
+    pub fn leave_group(&mut self, group_id: u32) -> Result<(), GroupError> {
+        let account_id = env::predecessor_account_id();
         let group = self
             .groups
             .get_mut(&group_id)
             .ok_or(GroupError::GroupNotFound)?;
 
-        if !group.members.members.contains_key(&account_id) {
+        // An owner can only leave once someone else holds the group
+        if group.owner == account_id {
+            let successor = group
+                .members
+                .successor(&account_id, &group.succession_policy)
+                .ok_or(GroupError::OwnerCannotLeave)?;
+            group.set_owner(successor.clone(), ApplicationRole::Member);
+            self.ownership_transfers.remove(&group_id);
+            self.renew_membership_token(group_id, &successor);
+            env::log_str(
+                format!(
+                    "Ownership of group {} passed from {} to {} on leave",
+                    group_id, account_id, successor
+                )
+                .as_str(),
+            );
+        }
+
+        self.release_member(group_id, &account_id)
+    }
+
+    #[handle_result]
+    pub fn set_succession_policy(
+        &mut self,
+        group_id: u32,
+        policy: SuccessionPolicy,
+    ) -> Result<(), GroupError> {
+        let account_id = env::predecessor_account_id();
+        let group = self.get_owned_group_for_update(group_id, &account_id)?;
+        group.succession_policy = policy;
+        group.updated_on = env::block_timestamp();
+        self.index_group_listing(group_id);
+        env::log_str(format!("Succession policy of group {} updated", group_id).as_str());
+        Ok(())
+    }
+
+    /// Caps the number of members, `None` lifts the cap. Lowering it keeps the
+    /// current members, raising it admits waitlisted accounts into the new seats.
+    #[handle_result]
+    pub fn set_group_max_members(
+        &mut self,
+        group_id: u32,
+        max_members: Option<u32>,
+    ) -> Result<(), GroupError> {
+        let account_id = env::predecessor_account_id();
+        let group = self.get_group_for_update(group_id, &account_id, Permission::ManageMembers)?;
+        group.max_members = max_members;
+        group.updated_on = env::block_timestamp();
+        self.index_group_listing(group_id);
+
+        env::log_str(format!("Member limit of group {} updated", group_id).as_str());
+        self.fill_from_waitlist(group_id);
+        Ok(())
+    }
+
+    #[handle_result]
+    pub fn add_group_member(
+        &mut self,
+        group_id: u32,
+        account_id: AccountId,
+        role: Option<ApplicationRole>,
+    ) -> Result<(), GroupError> {
+        let caller = env::predecessor_account_id();
+        let role = role.unwrap_or_default();
+        let group = self.get_group_for_update(group_id, &caller, Permission::ManageMembers)?;
+
+        if role == ApplicationRole::Owner {
+            return Err(GroupError::InvalidRole);
+        }
+        if !group.can_assign_role(&caller, &role) {
+            return Err(GroupError::Unauthorized);
+        }
+
+        self.admit_member(group_id, &account_id, role.clone(), Some(caller.clone()))?;
+        env::log_str(
+            format!(
+                "Member {} added to group {} as {} by {}",
+                account_id, group_id, role, caller
+            )
+            .as_str(),
+        );
+        Ok(())
+    }
+
+    #[handle_result]
+    pub fn remove_group_member(
+        &mut self,
+        group_id: u32,
+        account_id: AccountId,
+    ) -> Result<(), GroupError> {
+        let caller = env::predecessor_account_id();
+        let group = self.get_group_for_update(group_id, &caller, Permission::ManageMembers)?;
+
+        if !group.is_member(account_id.clone()) {
             return Err(GroupError::NotMember);
         }
-        group.members.members.remove(&account_id);
+        if !group.can_assign_role(&caller, &group.role_of(&account_id)) {
+            return Err(GroupError::Unauthorized);
+        }
 
+        self.release_member(group_id, &account_id)?;
+        env::log_str(
+            format!(
+                "Member {} removed from group {} by {}",
+                account_id, group_id, caller
+            )
+            .as_str(),
+        );
         Ok(())
     }
 
-    pub fn get_user_groups(&self, account_id: AccountId) -> Vec<u32> {
+    #[handle_result]
+    pub fn set_group_member_role(
+        &mut self,
+        group_id: u32,
+        account_id: AccountId,
+        role: ApplicationRole,
+    ) -> Result<(), GroupError> {
+        let caller = env::predecessor_account_id();
+        let group = self.get_group_for_update(group_id, &caller, Permission::ManageRoles)?;
+
+        // Ownership only changes hands through a transfer
+        if role == ApplicationRole::Owner {
+            return Err(GroupError::InvalidRole);
+        }
+        if !group.is_member(account_id.clone()) {
+            return Err(GroupError::NotMember);
+        }
+        let previous_role = group.role_of(&account_id);
+        if !group.can_assign_role(&caller, &previous_role) || !group.can_assign_role(&caller, &role)
+        {
+            return Err(GroupError::Unauthorized);
+        }
+
+        group.set_member_role(account_id.clone(), role.clone());
+        group.updated_on = env::block_timestamp();
+        self.index_group_listing(group_id);
+        env::log_str(
+            format!(
+                "Member {} changed from {} to {} in group {} by {}",
+                account_id, previous_role, role, group_id, caller
+            )
+            .as_str(),
+        );
+        Ok(())
+    }
+
+    pub fn get_user_groups(
+        &self,
+        account_id: AccountId,
+        include_deleted: Option<bool>,
+    ) -> Vec<u32> {
         self.profiles
             .get(&account_id)
-            .map(|profile| profile.joined_groups.clone())
+            .map(|profile| {
+                profile
+                    .joined_groups
+                    .iter()
+                    .copied()
+                    .filter(|group_id| self.visible_group(*group_id, include_deleted).is_some())
+                    .collect()
+            })
             .unwrap_or_default()
     }
 
-    pub fn is_user_in_group(&self, account_id: AccountId, group_id: u32) -> bool {
-        self.groups
-            .get(&group_id)
-            .map(|group| group.members.members.contains_key(&account_id))
+    pub fn is_user_in_group(
+        &self,
+        account_id: AccountId,
+        group_id: u32,
+        include_deleted: Option<bool>,
+    ) -> bool {
+        self.visible_group(group_id, include_deleted)
+            .map(|group| group.members.is_active_member(&account_id))
             .unwrap_or(false)
     }
 
-    pub fn get_group_members(&self, group_id: u32) -> HashMap<AccountId, ApplicationRole> {
-        self.groups
-            .get(&group_id)
-            .map(|group| group.members.members.clone())
+    pub fn get_group_members(
+        &self,
+        group_id: u32,
+        include_deleted: Option<bool>,
+    ) -> HashMap<AccountId, ApplicationRole> {
+        self.visible_group(group_id, include_deleted)
+            .map(|group| {
+                group
+                    .members
+                    .members
+                    .iter()
+                    .map(|(account_id, member)| (account_id.clone(), member.role.clone()))
+                    .collect()
+            })
             .unwrap_or_default()
     }
 
+    /// Members of a group, optionally filtered by role. Without `sort` the
+    /// members come ordered by account id.
+    pub fn get_group_members_page(
+        &self,
+        group_id: u32,
+        filter: Option<MemberFilter>,
+        sort: Option<MemberSort>,
+        order: Option<SortOrder>,
+        index: u32,
+        limit: u32,
+    ) -> Vec<MemberResponse> {
+        let MemberFilter {
+            role,
+            include_deleted,
+        } = filter.unwrap_or_default();
+        let Some(group) = self.visible_group(group_id, include_deleted) else {
+            return vec![];
+        };
+        let descending = order.unwrap_or_default() == SortOrder::Desc;
+        group
+            .members
+            .sorted(&sort.unwrap_or_default(), descending)
+            .filter(|(_, member)| role.as_ref().is_none_or(|role| member.role == *role))
+            .skip(index as _)
+            .take(limit as _)
+            .map(|(account_id, member)| MemberResponse::new(account_id.clone(), member))
+            .collect()
+    }
+
+    /// Members of a group that linked a Matrix user id to their profile.
+    pub fn get_group_matrix_members(
+        &self,
+        group_id: u32,
+        index: u32,
+        limit: u32,
+    ) -> Vec<MatrixMember> {
+        let Some(group) = self.groups.get(&group_id) else {
+            return vec![];
+        };
+        group
+            .members
+            .active()
+            .filter_map(|(account_id, _)| {
+                let matrix_user_id = self.profiles.get(account_id)?.matrix_user_id.clone()?;
+                Some(MatrixMember {
+                    account_id: account_id.clone(),
+                    matrix_user_id,
+                })
+            })
+            .skip(index as _)
+            .take(limit as _)
+            .collect()
+    }
+
     pub fn get_user_role_in_group(
         &self,
         account_id: AccountId,
         group_id: u32,
+        include_deleted: Option<bool>,
     ) -> Option<ApplicationRole> {
+        self.visible_group(group_id, include_deleted)
+            .and_then(|group| group.members.role_of(&account_id).cloned())
+    }
+
+    /// Overrides the permissions of a role in a group, passing `None` restores the defaults.
+    #[handle_result]
+    pub fn set_group_role_permissions(
+        &mut self,
+        group_id: u32,
+        role: ApplicationRole,
+        permissions: Option<Vec<Permission>>,
+    ) -> Result<(), GroupError> {
+        let account_id = env::predecessor_account_id();
+        let group = self.get_group_for_update(group_id, &account_id, Permission::ManageRoles)?;
+
+        if !role.permissions_can_be_overridden() {
+            return Err(GroupError::InvalidRole);
+        }
+        // Nobody can change the permissions of their own role or one above it
+        let caller_role = group.role_of(&account_id);
+        if !caller_role.outranks(&role) {
+            return Err(GroupError::Unauthorized);
+        }
+        // Nor hand out a permission they don't hold themselves
+        let is_owner = group.owner == account_id;
+        let caller_permissions = group.permissions_of(&account_id);
+        let current = group.permissions.for_role(&role);
+        let granted = permissions
+            .clone()
+            .unwrap_or_else(|| role.default_permissions());
+        if granted
+            .iter()
+            .filter(|permission| !current.contains(permission))
+            .any(|permission| {
+                !caller_permissions.contains(permission)
+                    || (permission.is_owner_only() && !is_owner)
+            })
+        {
+            return Err(GroupError::Unauthorized);
+        }
+
+        group.permissions.set(role.clone(), permissions);
+        group.updated_on = env::block_timestamp();
+        self.index_group_listing(group_id);
+        env::log_str(
+            format!(
+                "Permissions of role {} in group {} updated by {}",
+                role, group_id, account_id
+            )
+            .as_str(),
+        );
+        Ok(())
+    }
+
+    pub fn get_group_role_permissions(
+        &self,
+        group_id: u32,
+    ) -> HashMap<ApplicationRole, Vec<Permission>> {
+        self.groups
+            .get(&group_id)
+            .map(|group| group.permissions.matrix())
+            .unwrap_or_default()
+    }
+
+    pub fn get_user_permissions_in_group(
+        &self,
+        account_id: AccountId,
+        group_id: u32,
+    ) -> Vec<Permission> {
         self.groups
             .get(&group_id)
-            .and_then(|group| group.members.members.get(&account_id).cloned())
+            .map(|group| group.permissions_of(&account_id))
+            .unwrap_or_default()
+    }
+}
+
+impl Contract {
+    /// Names are unique by their normalized form and by their slug, deleted groups
+    /// hold on to their name until they are purged.
+    pub(crate) fn check_group_name_available(
+        &self,
+        name: &str,
+        group_id: u32,
+    ) -> Result<(), GroupError> {
+        let slug = group_slug(name);
+        if slug.is_empty() {
+            return Err(GroupError::InvalidGroupName);
+        }
+        let taken_by_other = |id: Option<&u32>| id.is_some_and(|id| *id != group_id);
+        if taken_by_other(self.group_names.get(&normalize_group_name(name)))
+            || taken_by_other(self.group_slugs.get(&slug))
+        {
+            return Err(GroupError::GroupNameTaken);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn index_group_name(&mut self, group_id: u32, name: &str) {
+        self.group_names
+            .insert(normalize_group_name(name), group_id);
+        self.group_slugs.insert(group_slug(name), group_id);
+    }
+
+    pub(crate) fn unindex_group_name(&mut self, group_id: u32, name: &str) {
+        let normalized = normalize_group_name(name);
+        if self.group_names.get(&normalized) == Some(&group_id) {
+            self.group_names.remove(&normalized);
+        }
+        let slug = group_slug(name);
+        if self.group_slugs.get(&slug) == Some(&group_id) {
+            self.group_slugs.remove(&slug);
+        }
+    }
+
+    /// Validates and applies an update, keeping the group's indexes in sync. Callers
+    /// check who may update the group.
+    pub(crate) fn apply_group_update(
+        &mut self,
+        id: u32,
+        update_group: UpdateGroup,
+    ) -> Result<(), GroupError> {
+        let current_group = self.groups.get(&id).ok_or(GroupError::GroupNotFound)?;
+        let previous_name = current_group.name.clone();
+        let previous_description = current_group.description.clone();
+        let previous_tags = current_group.tags.clone();
+        let previous_location = current_group.location.clone();
+
+        if let Some(name) = &update_group.name {
+            self.check_group_name_available(name, id)?;
+        }
+        if update_group
+            .location
+            .as_ref()
+            .is_some_and(|location| !location.is_valid())
+        {
+            return Err(GroupError::InvalidGeohash);
+        }
+        let current_group = self.groups.get_mut(&id).ok_or(GroupError::GroupNotFound)?;
+        current_group.update(update_group);
+
+        let name = current_group.name.clone();
+        let description = current_group.description.clone();
+        let tags = current_group.tags.clone();
+        let location = current_group.location.clone();
+        if name != previous_name {
+            self.unindex_group_name(id, &previous_name);
+            self.index_group_name(id, &name);
+        }
+        if name != previous_name || description != previous_description {
+            self.unindex_group_text(id, &previous_name, &previous_description);
+            self.index_group_text(id, &name, &description);
+        }
+        if tags != previous_tags {
+            self.unindex_group_tags(id, &previous_tags);
+            self.index_group_tags(id, &tags);
+        }
+        if location != previous_location {
+            self.unindex_group_location(id, &previous_location);
+            self.index_group_location(id, &location);
+        }
+        self.index_group_listing(id);
+        Ok(())
+    }
+
+    /// Every group mutation goes through here: it only hands out the group when
+    /// the caller's role in it grants the required permission.
+    pub(crate) fn get_group_for_update(
+        &mut self,
+        group_id: u32,
+        account_id: &AccountId,
+        permission: Permission,
+    ) -> Result<&mut GroupWithMembers, GroupError> {
+        let group = self
+            .groups
+            .get_mut(&group_id)
+            .ok_or(GroupError::GroupNotFound)?;
+
+        if group.is_deleted {
+            return Err(GroupError::GroupDeleted);
+        }
+        if !group.has_permission(account_id, permission) {
+            return Err(GroupError::Unauthorized);
+        }
+        Ok(group)
+    }
+
+    /// The group as read APIs see it, deleted groups only when `include_deleted` is set.
+    fn visible_group(
+        &self,
+        group_id: u32,
+        include_deleted: Option<bool>,
+    ) -> Option<&GroupWithMembers> {
+        self.groups
+            .get(&group_id)
+            .filter(|group| !group.is_deleted || include_deleted.unwrap_or(false))
+    }
+
+    /// Like `get_group_for_update`, for the settings only the owner may change.
+    pub(crate) fn get_owned_group_for_update(
+        &mut self,
+        group_id: u32,
+        account_id: &AccountId,
+    ) -> Result<&mut GroupWithMembers, GroupError> {
+        let group = self
+            .groups
+            .get_mut(&group_id)
+            .ok_or(GroupError::GroupNotFound)?;
+
+        if group.is_deleted {
+            return Err(GroupError::GroupDeleted);
+        }
+        if group.owner != *account_id {
+            return Err(GroupError::Unauthorized);
+        }
+        Ok(group)
+    }
+
+    /// Checks whether an account may join a group, without looking at free seats.
+    pub(crate) fn check_can_admit(
+        &self,
+        group_id: u32,
+        account_id: &AccountId,
+    ) -> Result<(), GroupError> {
+        if self.is_banned(group_id, account_id) {
+            return Err(GroupError::Banned);
+        }
+        self.check_parent_membership(group_id, account_id)?;
+
+        let profile = self
+            .profiles
+            .get(account_id)
+            .ok_or(GroupError::ProfileNotFound)?;
+
+        if profile.joined_groups.contains(&group_id) {
+            return Err(GroupError::AlreadyMember);
+        }
+
+        let group = self
+            .groups
+            .get(&group_id)
+            .ok_or(GroupError::GroupNotFound)?;
+
+        if group.is_deleted {
+            return Err(GroupError::GroupDeleted);
+        }
+        if group.is_member(account_id.clone()) {
+            return Err(GroupError::UserAlreadyInGroup);
+        }
+        Ok(())
+    }
+
+    /// Adds an account to a group, keeping its profile and rewards in sync.
+    pub(crate) fn admit_member(
+        &mut self,
+        group_id: u32,
+        account_id: &AccountId,
+        role: ApplicationRole,
+        added_by: Option<AccountId>,
+    ) -> Result<(), GroupError> {
+        self.check_can_admit(group_id, account_id)?;
+
+        // Update Group
+        let group = self
+            .groups
+            .get_mut(&group_id)
+            .ok_or(GroupError::GroupNotFound)?;
+
+        if group.is_full() {
+            return Err(GroupError::GroupFull);
+        }
+        group.add_member(account_id.clone(), role, added_by);
+        let mint_membership_token = group.mint_membership_tokens;
+        self.index_group_listing(group_id);
+
+        // Update Profile
+        if let Some(profile) = self.profiles.get_mut(account_id) {
+            profile.joined_groups.push(group_id);
+        }
+        // Joining some other way releases a join fee held by a request or the waitlist
+        if let Some(request) = self.take_join_request(group_id, account_id) {
+            self.refund_deposit(account_id, request.deposit.0);
+        }
+        if let Some(entry) = self.take_waitlist_entry(group_id, account_id) {
+            self.refund_deposit(account_id, entry.deposit.0);
+        }
+        if mint_membership_token {
+            self.mint_membership_token(group_id, account_id);
+        }
+
+        match self.rewards.get_mut(account_id) {
+            Some(reward) => {
+                reward.group_join(group_id);
+            }
+            None => {
+                let mut new_reward = Rewards::default();
+                new_reward.group_join(group_id);
+                self.rewards.insert(account_id.clone(), new_reward);
+            }
+        };
+
+        Ok(())
+    }
+
+    /// Removes an account from a group and from its profile's joined groups.
+    pub(crate) fn release_member(
+        &mut self,
+        group_id: u32,
+        account_id: &AccountId,
+    ) -> Result<(), GroupError> {
+        // Update Group
+        let group = self
+            .groups
+            .get_mut(&group_id)
+            .ok_or(GroupError::GroupNotFound)?;
+
+        if !group.is_member(account_id.clone()) {
+            return Err(GroupError::NotMember);
+        }
+        group.remove_member(account_id.clone());
+
+        // A pending transfer to someone who left can never be accepted
+        if self
+            .ownership_transfers
+            .get(&group_id)
+            .is_some_and(|transfer| transfer.to == *account_id)
+        {
+            self.ownership_transfers.remove(&group_id);
+        }
+
+        // Update Profile
+        if let Some(profile) = self.profiles.get_mut(account_id) {
+            profile.joined_groups.retain(|&x| x != group_id);
+        }
+        self.revoke_membership_token(group_id, account_id);
+        self.index_group_listing(group_id);
+
+        self.fill_from_waitlist(group_id);
+        Ok(())
     }
 }
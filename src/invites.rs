@@ -0,0 +1,148 @@
+use crate::{
+    error::GroupError,
+    models::{
+        application_role::ApplicationRole,
+        invites::{invite_id, GroupInvite, PostGroupInvite},
+        permissions::Permission,
+    },
+    Contract, ContractExt,
+};
+
+use near_sdk::{env, near};
+
+#[near]
+impl Contract {
+    /// Returns the invite code, the only place it can be read from. Invites are
+    /// stored and managed under the hash of their code.
+    #[handle_result]
+    pub fn create_group_invite(
+        &mut self,
+        group_id: u32,
+        post_group_invite: PostGroupInvite,
+    ) -> Result<String, GroupError> {
+        let account_id = env::predecessor_account_id();
+        let group = self.get_group_for_update(group_id, &account_id, Permission::ManageMembers)?;
+
+        if let Some(role) = &post_group_invite.role {
+            if *role == ApplicationRole::Owner || !group.can_assign_role(&account_id, role) {
+                return Err(GroupError::InvalidRole);
+            }
+        }
+
+        let code = self.generate_invite_code(group_id);
+        let id = invite_id(&code);
+        self.group_invites.insert(
+            id.clone(),
+            GroupInvite::new(id.clone(), group_id, post_group_invite),
+        );
+        env::log_str(
+            format!(
+                "Invite {} for group {} created by {}",
+                id, group_id, account_id
+            )
+            .as_str(),
+        );
+        Ok(code)
+    }
+
+    #[handle_result]
+    pub fn revoke_group_invite(&mut self, invite_id: String) -> Result<(), GroupError> {
+        let account_id = env::predecessor_account_id();
+        let group_id = self
+            .group_invites
+            .get(&invite_id)
+            .ok_or(GroupError::InviteNotFound)?
+            .group_id;
+        self.get_group_for_update(group_id, &account_id, Permission::ManageMembers)?;
+
+        if let Some(invite) = self.group_invites.get_mut(&invite_id) {
+            invite.is_revoked = true;
+        }
+        env::log_str(
+            format!(
+                "Invite {} for group {} revoked by {}",
+                invite_id, group_id, account_id
+            )
+            .as_str(),
+        );
+        Ok(())
+    }
+
+    /// Joins the group of an invite, the join fee has to be attached like for `join_group`.
+    #[payable]
+    #[handle_result]
+    pub fn join_group_with_invite(&mut self, code: String) -> Result<(), GroupError> {
+        let account_id = env::predecessor_account_id();
+        let deposit = env::attached_deposit().as_yoctonear();
+        let id = invite_id(&code);
+        let invite = self
+            .group_invites
+            .get(&id)
+            .ok_or(GroupError::InviteNotFound)?;
+
+        if invite.is_revoked {
+            return Err(GroupError::InviteRevoked);
+        }
+        if invite.is_expired() {
+            return Err(GroupError::InviteExpired);
+        }
+        if invite.is_used_up() {
+            return Err(GroupError::InviteUsedUp);
+        }
+
+        let group_id = invite.group_id;
+        let role = invite.role.clone().unwrap_or_default();
+        let invited_by = invite.created_by.clone();
+        if deposit < self.join_fee_of(group_id) {
+            return Err(GroupError::InsufficientDeposit);
+        }
+        self.admit_member(group_id, &account_id, role, Some(invited_by))?;
+        self.collect_join_fee(group_id, &account_id, deposit);
+
+        if let Some(invite) = self.group_invites.get_mut(&id) {
+            invite.redeem(account_id.clone());
+        }
+        env::log_str(
+            format!(
+                "Invite {} for group {} redeemed by {}",
+                id, group_id, account_id
+            )
+            .as_str(),
+        );
+        Ok(())
+    }
+
+    /// Active invites of a group, `include_inactive` adds revoked, expired and used up ones.
+    pub fn get_group_invites(
+        &self,
+        group_id: u32,
+        include_inactive: Option<bool>,
+    ) -> Vec<GroupInvite> {
+        let include_inactive = include_inactive.unwrap_or(false);
+        self.group_invites
+            .values()
+            .filter(|invite| invite.group_id == group_id)
+            .filter(|invite| include_inactive || invite.is_active())
+            .cloned()
+            .collect()
+    }
+
+    pub fn get_group_invite(&self, invite_id: String) -> Option<GroupInvite> {
+        self.group_invites.get(&invite_id).cloned()
+    }
+}
+
+impl Contract {
+    fn generate_invite_code(&mut self, group_id: u32) -> String {
+        self.invite_counter += 1;
+
+        let mut seed = env::random_seed();
+        seed.extend_from_slice(&group_id.to_le_bytes());
+        seed.extend_from_slice(&self.invite_counter.to_le_bytes());
+
+        env::sha256(&seed)[..8]
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+}
@@ -0,0 +1,221 @@
+use crate::{
+    error::GroupError,
+    models::{
+        application_role::ApplicationRole, groups::JoinPolicy, join_requests::JoinRequest,
+        permissions::Permission,
+    },
+    Contract, ContractExt,
+};
+
+use near_sdk::{env, near, AccountId};
+
+#[near]
+impl Contract {
+    /// Asks to join a group, the join fee is held with the request until it is decided.
+    #[payable]
+    #[handle_result]
+    pub fn request_to_join_group(
+        &mut self,
+        group_id: u32,
+        message: Option<String>,
+    ) -> Result<(), GroupError> {
+        let account_id = env::predecessor_account_id();
+        let deposit = env::attached_deposit().as_yoctonear();
+        if !self.profiles.contains_key(&account_id) {
+            return Err(GroupError::ProfileNotFound);
+        }
+
+        let group = self
+            .groups
+            .get(&group_id)
+            .ok_or(GroupError::GroupNotFound)?;
+
+        if group.is_deleted {
+            return Err(GroupError::GroupDeleted);
+        }
+        if group.join_policy != JoinPolicy::RequestToJoin {
+            return Err(GroupError::JoinRequestsNotAccepted);
+        }
+        if group.is_member(account_id.clone()) {
+            return Err(GroupError::UserAlreadyInGroup);
+        }
+        if self.is_banned(group_id, &account_id) {
+            return Err(GroupError::Banned);
+        }
+        if group.join_fee.is_some_and(|join_fee| deposit < join_fee.0) {
+            return Err(GroupError::InsufficientDeposit);
+        }
+
+        let requests = self.join_requests.entry(group_id).or_default();
+        if requests
+            .iter()
+            .any(|request| request.account_id == account_id)
+        {
+            return Err(GroupError::JoinRequestAlreadyPending);
+        }
+        requests.push(JoinRequest::new(
+            group_id,
+            account_id.clone(),
+            message.unwrap_or_default(),
+            deposit,
+        ));
+
+        env::log_str(
+            format!(
+                "Join request for group {} added by {}",
+                group_id, account_id
+            )
+            .as_str(),
+        );
+        Ok(())
+    }
+
+    #[handle_result]
+    pub fn cancel_join_request(&mut self, group_id: u32) -> Result<(), GroupError> {
+        let account_id = env::predecessor_account_id();
+        let request = self
+            .take_join_request(group_id, &account_id)
+            .ok_or(GroupError::JoinRequestNotFound)?;
+        self.refund_deposit(&account_id, request.deposit.0);
+        env::log_str(
+            format!(
+                "Join request for group {} cancelled by {}",
+                group_id, account_id
+            )
+            .as_str(),
+        );
+        Ok(())
+    }
+
+    /// Admits every requesting account in `account_ids`, returns the ones that were admitted.
+    /// The held deposit pays the join fee. Requests that can't be admitted, for example
+    /// because the group is full, stay pending.
+    #[handle_result]
+    pub fn approve_join_requests(
+        &mut self,
+        group_id: u32,
+        account_ids: Vec<AccountId>,
+    ) -> Result<Vec<AccountId>, GroupError> {
+        let caller = env::predecessor_account_id();
+        self.get_group_for_update(group_id, &caller, Permission::ManageMembers)?;
+
+        let mut approved = vec![];
+        for account_id in account_ids {
+            let Some(position) = self.join_request_position(group_id, &account_id) else {
+                continue;
+            };
+            // Taken first so admitting doesn't refund its deposit, put back if admitting fails
+            let Some(request) = self.take_join_request(group_id, &account_id) else {
+                continue;
+            };
+            let admitted = if request.deposit.0 < self.join_fee_of(group_id) {
+                Err(GroupError::InsufficientDeposit)
+            } else {
+                self.admit_member(
+                    group_id,
+                    &account_id,
+                    ApplicationRole::Member,
+                    Some(caller.clone()),
+                )
+            };
+            match admitted {
+                Ok(()) => {
+                    self.collect_join_fee(group_id, &account_id, request.deposit.0);
+                    env::log_str(
+                        format!(
+                            "Join request of {} for group {} approved by {}",
+                            account_id, group_id, caller
+                        )
+                        .as_str(),
+                    );
+                    approved.push(account_id);
+                }
+                Err(error) => {
+                    self.join_requests
+                        .entry(group_id)
+                        .or_default()
+                        .insert(position, request);
+                    env::log_str(
+                        format!(
+                            "Join request of {} for group {} kept pending: {}",
+                            account_id,
+                            group_id,
+                            error.as_ref()
+                        )
+                        .as_str(),
+                    );
+                }
+            }
+        }
+        Ok(approved)
+    }
+
+    /// Drops every request in `account_ids`, returns the ones that were pending.
+    #[handle_result]
+    pub fn reject_join_requests(
+        &mut self,
+        group_id: u32,
+        account_ids: Vec<AccountId>,
+    ) -> Result<Vec<AccountId>, GroupError> {
+        let caller = env::predecessor_account_id();
+        self.get_group_for_update(group_id, &caller, Permission::ManageMembers)?;
+
+        let mut rejected = vec![];
+        for account_id in account_ids {
+            if let Some(request) = self.take_join_request(group_id, &account_id) {
+                self.refund_deposit(&account_id, request.deposit.0);
+                env::log_str(
+                    format!(
+                        "Join request of {} for group {} rejected by {}",
+                        account_id, group_id, caller
+                    )
+                    .as_str(),
+                );
+                rejected.push(account_id);
+            }
+        }
+        Ok(rejected)
+    }
+
+    pub fn get_group_join_requests(&self, group_id: u32) -> Vec<JoinRequest> {
+        self.join_requests
+            .get(&group_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn get_user_join_requests(&self, account_id: AccountId) -> Vec<JoinRequest> {
+        self.join_requests
+            .values()
+            .flatten()
+            .filter(|request| request.account_id == account_id)
+            .cloned()
+            .collect()
+    }
+}
+
+impl Contract {
+    fn join_request_position(&self, group_id: u32, account_id: &AccountId) -> Option<usize> {
+        self.join_requests
+            .get(&group_id)?
+            .iter()
+            .position(|request| request.account_id == *account_id)
+    }
+
+    pub(crate) fn take_join_request(
+        &mut self,
+        group_id: u32,
+        account_id: &AccountId,
+    ) -> Option<JoinRequest> {
+        let requests = self.join_requests.get_mut(&group_id)?;
+        let position = requests
+            .iter()
+            .position(|request| request.account_id == *account_id)?;
+        let request = requests.remove(position);
+
+        if requests.is_empty() {
+            self.join_requests.remove(&group_id);
+        }
+        Some(request)
+    }
+}
@@ -1,14 +1,44 @@
 // Find all our documentation at https://docs.near.org
+pub mod bans;
 pub mod error;
+pub mod gates;
 pub mod groups;
+pub mod invites;
+pub mod join_requests;
+pub mod listing;
+pub mod locations;
+pub mod migrate;
 pub mod models;
+pub mod ownership;
+pub mod polls;
 pub mod profile;
+pub mod proposals;
 pub mod rewards;
+pub mod sbt;
+pub mod search;
+pub mod subgroups;
+pub mod subscriptions;
+pub mod tags;
+pub mod treasury;
+pub mod waitlists;
 
+use crate::models::bans::GroupBan;
 use crate::models::groups::GroupWithMembers;
+use crate::models::invites::GroupInvite;
+use crate::models::join_requests::JoinRequest;
+use crate::models::ownership_transfer::OwnershipTransfer;
+use crate::models::polls::Poll;
 use crate::models::profile::Profile;
+use crate::models::proposals::Proposal;
+use crate::models::sbt::MembershipToken;
+use crate::models::treasury::Treasury;
+use crate::models::waitlist::WaitlistEntry;
 use models::rewards::Rewards;
-use near_sdk::{near, store::IterableMap, AccountId};
+use near_sdk::{
+    near,
+    store::{IterableMap, LookupMap, TreeMap},
+    AccountId,
+};
 
 // Define the contract structure
 #[near(contract_state)]
@@ -17,6 +47,38 @@ pub struct Contract {
     pub groups: IterableMap<u32, GroupWithMembers>,
     pub rewards: IterableMap<AccountId, Rewards>,
     pub group_id_counter: u32,
+    pub ownership_transfers: IterableMap<u32, OwnershipTransfer>,
+    pub join_requests: IterableMap<u32, Vec<JoinRequest>>,
+    pub group_invites: IterableMap<String, GroupInvite>,
+    pub invite_counter: u64,
+    pub group_bans: IterableMap<u32, Vec<GroupBan>>,
+    pub groups_by_tag: IterableMap<u32, Vec<u32>>,
+    pub group_names: IterableMap<String, u32>,
+    pub group_slugs: IterableMap<String, u32>,
+    pub group_name_prefixes: IterableMap<String, Vec<u32>>,
+    pub group_keywords: IterableMap<String, Vec<u32>>,
+    pub group_waitlists: IterableMap<u32, Vec<WaitlistEntry>>,
+    pub group_children: IterableMap<u32, Vec<u32>>,
+    pub groups_by_location: IterableMap<String, Vec<u32>>,
+    pub membership_tokens: IterableMap<u64, MembershipToken>,
+    pub membership_tokens_by_owner: IterableMap<AccountId, Vec<u64>>,
+    pub membership_token_counter: u64,
+    pub group_treasuries: IterableMap<u32, Treasury>,
+    pub proposals: IterableMap<u64, Proposal>,
+    pub proposal_counter: u64,
+    pub group_proposals: IterableMap<u32, Vec<u64>>,
+    pub polls: IterableMap<u64, Poll>,
+    pub poll_counter: u64,
+    pub group_polls: IterableMap<u32, Vec<u64>>,
+    /// Account that confirms Matrix user id claims, set by the contract account
+    pub matrix_verifier: Option<AccountId>,
+    pub matrix_user_ids: IterableMap<String, AccountId>,
+    pub matrix_user_id_claims: IterableMap<AccountId, String>,
+    /// Listing order by `(updated_on, id)` and by `(members_count, id)`
+    pub groups_by_updated_on: TreeMap<(u64, u32), ()>,
+    pub groups_by_members_count: TreeMap<(u64, u32), ()>,
+    /// Keys each group is currently listed under, `(updated_on, members_count)`
+    pub group_listing_keys: LookupMap<u32, (u64, u64)>,
 }
 
 // Define the default, which automatically initializes the contract
@@ -27,6 +89,35 @@ impl Default for Contract {
             groups: IterableMap::new(b"g"),
             rewards: IterableMap::new(b"r"),
             group_id_counter: 0,
+            ownership_transfers: IterableMap::new(b"t"),
+            join_requests: IterableMap::new(b"j"),
+            group_invites: IterableMap::new(b"i"),
+            invite_counter: 0,
+            group_bans: IterableMap::new(b"b"),
+            groups_by_tag: IterableMap::new(b"k"),
+            group_names: IterableMap::new(b"n"),
+            group_slugs: IterableMap::new(b"s"),
+            group_name_prefixes: IterableMap::new(b"w"),
+            group_keywords: IterableMap::new(b"d"),
+            group_waitlists: IterableMap::new(b"q"),
+            group_children: IterableMap::new(b"c"),
+            groups_by_location: IterableMap::new(b"l"),
+            membership_tokens: IterableMap::new(b"o"),
+            membership_tokens_by_owner: IterableMap::new(b"h"),
+            membership_token_counter: 0,
+            group_treasuries: IterableMap::new(b"f"),
+            proposals: IterableMap::new(b"x"),
+            proposal_counter: 0,
+            group_proposals: IterableMap::new(b"zp".as_slice()),
+            polls: IterableMap::new(b"y"),
+            poll_counter: 0,
+            group_polls: IterableMap::new(b"zq".as_slice()),
+            matrix_verifier: None,
+            matrix_user_ids: IterableMap::new(b"a"),
+            matrix_user_id_claims: IterableMap::new(b"u"),
+            groups_by_updated_on: TreeMap::new(b"zu".as_slice()),
+            groups_by_members_count: TreeMap::new(b"zc".as_slice()),
+            group_listing_keys: LookupMap::new(b"zk".as_slice()),
         }
     }
 }
@@ -0,0 +1,115 @@
+use crate::{
+    models::{
+        groups::{GroupCursor, GroupFilter, GroupPage, GroupResponse, GroupSort},
+        sorting::SortOrder,
+    },
+    Contract, ContractExt,
+};
+
+use near_sdk::{near, store::TreeMap};
+use std::ops::Bound;
+
+#[near]
+impl Contract {
+    /// Groups ordered by `sort` with the id breaking ties. Pages continue after
+    /// the cursor instead of an offset, so groups added in between don't shift
+    /// them, and each page only reads the groups from the cursor onward.
+    /// `MembersCount` orders by the count at the group's last membership change,
+    /// memberships that expired since still count towards it.
+    pub fn get_groups_page(
+        &self,
+        filter: Option<GroupFilter>,
+        sort: Option<GroupSort>,
+        order: Option<SortOrder>,
+        cursor: Option<GroupCursor>,
+        limit: u32,
+    ) -> GroupPage {
+        let filter = filter.unwrap_or_default();
+        let sort = sort.unwrap_or_default();
+        let descending = order.unwrap_or_default() == SortOrder::Desc;
+        let after = cursor.map(|cursor| (cursor.key, cursor.id));
+
+        let positions: Box<dyn Iterator<Item = (u64, u32)> + '_> = match sort {
+            // Ids are handed out in creation order
+            GroupSort::CreatedOn => {
+                let ids: Box<dyn Iterator<Item = u32>> = match (after, descending) {
+                    (None, false) => Box::new(0..self.group_id_counter),
+                    (Some((_, id)), false) => Box::new(id.saturating_add(1)..self.group_id_counter),
+                    (None, true) => Box::new((0..self.group_id_counter).rev()),
+                    (Some((_, id)), true) => Box::new((0..id).rev()),
+                };
+                Box::new(
+                    ids.filter_map(|id| self.groups.get(&id).map(|group| (group.created_on, id))),
+                )
+            }
+            GroupSort::UpdatedOn => listed_after(&self.groups_by_updated_on, after, descending),
+            GroupSort::MembersCount => {
+                listed_after(&self.groups_by_members_count, after, descending)
+            }
+        };
+
+        let limit = limit as usize;
+        let mut page: Vec<(u64, u32, GroupResponse)> = positions
+            .filter_map(|(key, id)| self.groups.get(&id).map(|group| (key, id, group)))
+            .filter(|(_, _, group)| filter.matches(group))
+            .take(limit.saturating_add(1))
+            .map(|(key, id, group)| (key, id, GroupResponse::new(id, group)))
+            .collect();
+
+        let next_cursor = if limit > 0 && page.len() > limit {
+            page.truncate(limit);
+            page.last()
+                .map(|(key, id, _)| GroupCursor { key: *key, id: *id })
+        } else {
+            None
+        };
+
+        GroupPage {
+            groups: page.into_iter().map(|(_, _, group)| group).collect(),
+            next_cursor,
+        }
+    }
+}
+
+impl Contract {
+    /// Lists a group under its current update time and member count, replacing
+    /// where it was listed before. Called whenever either changes.
+    pub(crate) fn index_group_listing(&mut self, group_id: u32) {
+        self.unindex_group_listing(group_id);
+        let Some(group) = self.groups.get(&group_id) else {
+            return;
+        };
+        let (updated_on, members_count) = (group.updated_on, group.members.active_len() as u64);
+        self.groups_by_updated_on.insert((updated_on, group_id), ());
+        self.groups_by_members_count
+            .insert((members_count, group_id), ());
+        self.group_listing_keys
+            .insert(group_id, (updated_on, members_count));
+    }
+
+    pub(crate) fn unindex_group_listing(&mut self, group_id: u32) {
+        if let Some((updated_on, members_count)) = self.group_listing_keys.remove(&group_id) {
+            self.groups_by_updated_on.remove(&(updated_on, group_id));
+            self.groups_by_members_count
+                .remove(&(members_count, group_id));
+        }
+    }
+}
+
+fn listed_after(
+    listing: &TreeMap<(u64, u32), ()>,
+    after: Option<(u64, u32)>,
+    descending: bool,
+) -> Box<dyn Iterator<Item = (u64, u32)> + '_> {
+    let bounds = match (after, descending) {
+        (None, _) => (Bound::Unbounded, Bound::Unbounded),
+        (Some(after), false) => (Bound::Excluded(after), Bound::Unbounded),
+        (Some(after), true) => (Bound::Unbounded, Bound::Excluded(after)),
+    };
+    let positions = listing.range(bounds).map(|(position, _)| *position);
+    if descending {
+        Box::new(positions.rev())
+    } else {
+        Box::new(positions)
+    }
+}
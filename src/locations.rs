@@ -0,0 +1,101 @@
+use crate::{
+    models::{
+        groups::{GroupResponse, GroupVisibility},
+        location::{
+            country_key, geohash_key, normalize_place, region_key, GroupLocation,
+            GEOHASH_INDEX_PRECISION,
+        },
+    },
+    search::{add_to_index, remove_from_index},
+    Contract, ContractExt,
+};
+
+use near_sdk::near;
+
+#[near]
+impl Contract {
+    pub fn get_groups_by_country(
+        &self,
+        country: String,
+        index: u32,
+        limit: u32,
+    ) -> Vec<GroupResponse> {
+        let key = country_key(&normalize_place(&country));
+        self.groups_by_location_key(&key, |_| true, index, limit)
+    }
+
+    pub fn get_groups_by_region(
+        &self,
+        country: String,
+        state_or_province: String,
+        index: u32,
+        limit: u32,
+    ) -> Vec<GroupResponse> {
+        let key = region_key(
+            &normalize_place(&country),
+            &normalize_place(&state_or_province),
+        );
+        self.groups_by_location_key(&key, |_| true, index, limit)
+    }
+
+    /// Groups whose geohash starts with the prefix, a shorter prefix covers a larger area.
+    pub fn get_groups_by_geohash(
+        &self,
+        prefix: String,
+        index: u32,
+        limit: u32,
+    ) -> Vec<GroupResponse> {
+        let prefix = prefix.to_lowercase();
+        if prefix.is_empty() || !prefix.is_ascii() {
+            return vec![];
+        }
+        // Prefixes longer than the index holds are checked against the geohash itself
+        let key = geohash_key(&prefix[..prefix.len().min(GEOHASH_INDEX_PRECISION)]);
+        self.groups_by_location_key(
+            &key,
+            |location| {
+                location
+                    .geohash
+                    .as_ref()
+                    .is_some_and(|geohash| geohash.starts_with(&prefix))
+            },
+            index,
+            limit,
+        )
+    }
+}
+
+impl Contract {
+    pub(crate) fn index_group_location(&mut self, group_id: u32, location: &GroupLocation) {
+        for key in location.index_keys() {
+            add_to_index(&mut self.groups_by_location, key, group_id);
+        }
+    }
+
+    pub(crate) fn unindex_group_location(&mut self, group_id: u32, location: &GroupLocation) {
+        for key in location.index_keys() {
+            remove_from_index(&mut self.groups_by_location, key, group_id);
+        }
+    }
+
+    fn groups_by_location_key(
+        &self,
+        key: &str,
+        matches: impl Fn(&GroupLocation) -> bool,
+        index: u32,
+        limit: u32,
+    ) -> Vec<GroupResponse> {
+        self.groups_by_location
+            .get(key)
+            .into_iter()
+            .flatten()
+            .filter_map(|id| self.groups.get(id).map(|group| (*id, group)))
+            .filter(|(_, group)| !group.is_deleted)
+            .filter(|(_, group)| group.visibility == GroupVisibility::Public)
+            .filter(|(_, group)| matches(&group.location))
+            .skip(index as _)
+            .take(limit as _)
+            .map(|(id, group)| GroupResponse::new(id, group))
+            .collect()
+    }
+}
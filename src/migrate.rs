@@ -0,0 +1,210 @@
+use crate::{
+    models::{
+        application_role::ApplicationRole,
+        groups::GroupWithMembers,
+        members::{Member, Members},
+        profile::Profile,
+        rewards::Rewards,
+    },
+    Contract, ContractExt,
+};
+
+use near_sdk::{env, near, store::IterableMap, AccountId};
+use std::collections::HashMap;
+
+/// Group layout of the first release, before the members moved into their own collection.
+#[near(serializers = ["borsh"])]
+pub struct OldGroupWithMembers {
+    pub name: String,
+    pub description: String,
+    pub website: String,
+    pub image: String,
+    pub banner_image: String,
+    pub owner: AccountId,
+    pub created_by: AccountId,
+    pub members: OldMembers,
+    pub matrix_space_id: String,
+    pub is_deleted: bool,
+    pub updated_on: u64,
+    pub created_on: u64,
+}
+
+#[near(serializers = ["borsh"])]
+pub struct OldMembers {
+    pub members: HashMap<AccountId, ApplicationRole>,
+}
+
+/// Profile layout of the first release, before Matrix user ids were linked.
+#[near(serializers = ["borsh"])]
+pub struct OldProfile {
+    pub username: String,
+    pub display_name: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub about: String,
+    pub email: String,
+    pub date_of_birth: u64,
+    pub city: String,
+    pub state_or_province: String,
+    pub country: String,
+    pub profile_image: String,
+    pub banner_image: String,
+    pub website: String,
+    pub application_role: ApplicationRole,
+    pub joined_groups: Vec<u32>,
+    pub skills: Vec<u32>,
+    pub interests: Vec<u32>,
+    pub causes: Vec<u32>,
+    pub extra: String,
+    pub updated_on: u64,
+    pub created_on: u64,
+}
+
+#[near(serializers = ["borsh"])]
+pub struct OldContract {
+    pub profiles: IterableMap<AccountId, OldProfile>,
+    pub groups: IterableMap<u32, OldGroupWithMembers>,
+    pub rewards: IterableMap<AccountId, Rewards>,
+    pub group_id_counter: u32,
+}
+
+impl OldProfile {
+    fn migrate(self) -> Profile {
+        Profile {
+            username: self.username,
+            display_name: self.display_name,
+            first_name: self.first_name,
+            last_name: self.last_name,
+            about: self.about,
+            email: self.email,
+            date_of_birth: self.date_of_birth,
+            city: self.city,
+            state_or_province: self.state_or_province,
+            country: self.country,
+            profile_image: self.profile_image,
+            banner_image: self.banner_image,
+            website: self.website,
+            matrix_user_id: None,
+            application_role: self.application_role,
+            joined_groups: self.joined_groups,
+            skills: self.skills,
+            interests: self.interests,
+            causes: self.causes,
+            extra: self.extra,
+            updated_on: self.updated_on,
+            created_on: self.created_on,
+        }
+    }
+}
+
+impl OldGroupWithMembers {
+    fn migrate(self, group_id: u32) -> GroupWithMembers {
+        // Join times were never recorded, members are treated as joining with the group
+        let mut members = Members::new(group_id);
+        for (account_id, role) in self.members.members {
+            members.insert(
+                account_id,
+                Member {
+                    role,
+                    joined_on: self.created_on,
+                    added_by: None,
+                    expires_on: None,
+                },
+            );
+        }
+
+        GroupWithMembers {
+            name: self.name,
+            description: self.description,
+            website: self.website,
+            image: self.image,
+            banner_image: self.banner_image,
+            owner: self.owner,
+            created_by: self.created_by,
+            members,
+            permissions: Default::default(),
+            succession_policy: Default::default(),
+            visibility: Default::default(),
+            join_policy: Default::default(),
+            tags: vec![],
+            max_members: None,
+            parent_id: None,
+            requires_parent_membership: false,
+            location: Default::default(),
+            gate: None,
+            mint_membership_tokens: false,
+            join_fee: None,
+            membership_duration: None,
+            governance: Default::default(),
+            matrix_space_id: self.matrix_space_id,
+            is_deleted: self.is_deleted,
+            deleted_on: self.is_deleted.then_some(self.updated_on),
+            updated_on: self.updated_on,
+            created_on: self.created_on,
+        }
+    }
+}
+
+#[near]
+impl Contract {
+    /// Upgrades state written by the first release: the members of every group
+    /// move out of the group itself and into their own collection, and profiles
+    /// get their new fields. Runs in a single transaction, so it has to be called
+    /// while the number of memberships and profiles still fits in the gas limit.
+    #[private]
+    #[init(ignore_state)]
+    pub fn migrate() -> Self {
+        let mut old_state: OldContract = env::state_read().expect("Failed to read old state");
+
+        let old_groups: Vec<(u32, OldGroupWithMembers)> = old_state.groups.drain().collect();
+        old_state.groups.flush();
+
+        let mut groups = IterableMap::new(b"g");
+        for (group_id, group) in old_groups {
+            groups.insert(group_id, group.migrate(group_id));
+        }
+
+        let old_profiles: Vec<(AccountId, OldProfile)> = old_state.profiles.drain().collect();
+        old_state.profiles.flush();
+
+        let mut profiles = IterableMap::new(b"p");
+        for (account_id, profile) in old_profiles {
+            profiles.insert(account_id, profile.migrate());
+        }
+
+        env::log_str(
+            format!(
+                "Migrated {} groups and {} profiles",
+                groups.len(),
+                profiles.len()
+            )
+            .as_str(),
+        );
+        let mut contract = Self {
+            profiles,
+            groups,
+            rewards: old_state.rewards,
+            group_id_counter: old_state.group_id_counter,
+            ..Default::default()
+        };
+
+        // Build the name, search and listing indexes, older groups may share a name
+        // and the first one keeps it
+        let texts: Vec<(u32, String, String)> = contract
+            .groups
+            .iter()
+            .map(|(id, group)| (*id, group.name.clone(), group.description.clone()))
+            .collect();
+        for (group_id, name, description) in texts {
+            contract.index_group_text(group_id, &name, &description);
+            contract.index_group_listing(group_id);
+            match contract.check_group_name_available(&name, group_id) {
+                Ok(()) => contract.index_group_name(group_id, &name),
+                Err(error) => env::log_str(
+                    format!("Group {} not indexed by name: {}", group_id, error.as_ref()).as_str(),
+                ),
+            }
+        }
+        contract
+    }
+}
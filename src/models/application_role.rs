@@ -1,12 +1,20 @@
 use core::fmt;
 use near_sdk::near;
 
+use super::permissions::Permission;
+
 #[near(serializers=["json", "borsh"])]
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+// Variants are stored by position, new ones go at the end to keep existing state readable
 pub enum ApplicationRole {
     Owner,
     #[default]
     Member,
+    Admin,
+    Moderator,
+    Guest,
+    Banned,
+    Treasurer,
 }
 
 impl fmt::Display for ApplicationRole {
@@ -14,7 +22,55 @@ impl fmt::Display for ApplicationRole {
         use ApplicationRole::*;
         match self {
             Owner => write!(f, "Owner"),
+            Admin => write!(f, "Admin"),
+            Moderator => write!(f, "Moderator"),
             Member => write!(f, "Member"),
+            Guest => write!(f, "Guest"),
+            Banned => write!(f, "Banned"),
+            Treasurer => write!(f, "Treasurer"),
+        }
+    }
+}
+
+impl ApplicationRole {
+    /// Position in the role hierarchy, higher outranks lower.
+    pub fn rank(&self) -> u8 {
+        use ApplicationRole::*;
+        match self {
+            Owner => 5,
+            Admin => 4,
+            Moderator | Treasurer => 3,
+            Member => 2,
+            Guest => 1,
+            Banned => 0,
+        }
+    }
+
+    pub fn outranks(&self, other: &ApplicationRole) -> bool {
+        self.rank() > other.rank()
+    }
+
+    pub fn default_permissions(&self) -> Vec<Permission> {
+        use ApplicationRole::*;
+        match self {
+            Owner => Permission::ALL.to_vec(),
+            Admin => vec![
+                Permission::EditMetadata,
+                Permission::ManageMembers,
+                Permission::ModerateContent,
+                Permission::ManageRoles,
+                Permission::ManagePolls,
+                Permission::CreateSubgroups,
+            ],
+            Moderator => vec![Permission::ModerateContent],
+            Treasurer => vec![Permission::ManageTreasury],
+            Member | Guest | Banned => vec![],
         }
     }
+
+    /// Owners always keep every permission and banned accounts never get any,
+    /// so neither can be changed per group.
+    pub fn permissions_can_be_overridden(&self) -> bool {
+        !matches!(self, ApplicationRole::Owner | ApplicationRole::Banned)
+    }
 }
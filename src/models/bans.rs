@@ -0,0 +1,28 @@
+use near_sdk::{env, near, AccountId};
+
+#[derive(Clone, Debug)]
+#[near(serializers = ["json", "borsh"])]
+pub struct GroupBan {
+    pub account_id: AccountId,
+    pub banned_by: AccountId,
+    pub reason: String,
+    pub banned_on: u64,
+    pub expires_on: Option<u64>, // banned for good when not set
+}
+
+impl GroupBan {
+    pub fn new(account_id: AccountId, reason: Option<String>, expires_on: Option<u64>) -> Self {
+        Self {
+            account_id,
+            banned_by: env::predecessor_account_id(),
+            reason: reason.unwrap_or_default(),
+            banned_on: env::block_timestamp(),
+            expires_on,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.expires_on
+            .is_none_or(|expires_on| env::block_timestamp() < expires_on)
+    }
+}
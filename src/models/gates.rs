@@ -0,0 +1,22 @@
+use near_sdk::{json_types::U128, near, AccountId};
+
+/// Holding an external token an account needs before it can join a group.
+#[derive(Clone, Debug, PartialEq)]
+#[near(serializers = ["json", "borsh"])]
+pub enum MembershipGate {
+    /// A minimum balance of a NEP-141 fungible token
+    FungibleToken {
+        contract_id: AccountId,
+        min_balance: U128,
+    },
+    /// Any token of a NEP-171 non-fungible token collection
+    NonFungibleToken { contract_id: AccountId },
+}
+
+/// The part of a NEP-171 token the gate reads.
+#[derive(Clone, Debug)]
+#[near(serializers = ["json"])]
+pub struct NftToken {
+    pub token_id: String,
+    pub owner_id: AccountId,
+}
@@ -1,9 +1,34 @@
 use super::application_role::ApplicationRole;
-use crate::models::members::Members;
-use near_sdk::{env, near, AccountId};
+use super::gates::MembershipGate;
+use super::location::GroupLocation;
+use super::permissions::{GroupPermissions, Permission};
+use super::proposals::GovernanceSettings;
+use crate::models::members::{Member, Members, SuccessionPolicy};
+use near_sdk::{env, json_types::U128, near, AccountId};
 
-#[derive(Clone, Debug)]
+/// Time in nanoseconds a deleted group can still be restored by its owner.
+pub const GROUP_DELETION_GRACE_PERIOD: u64 = 30 * 24 * 60 * 60 * 1_000_000_000;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[near(serializers = ["json", "borsh"])]
+pub enum GroupVisibility {
+    #[default]
+    Public,
+    /// Left out of the group listings, still reachable by id
+    Private,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
 #[near(serializers = ["json", "borsh"])]
+pub enum JoinPolicy {
+    #[default]
+    Open,
+    RequestToJoin,
+    InviteOnly,
+}
+
+#[derive(Debug)]
+#[near(serializers = ["borsh"])]
 pub struct GroupWithMembers {
     pub name: String,
     pub description: String,
@@ -13,31 +38,32 @@ pub struct GroupWithMembers {
     pub owner: AccountId,
     pub created_by: AccountId,
     pub members: Members,
+    pub permissions: GroupPermissions,
+    pub succession_policy: SuccessionPolicy,
+    pub visibility: GroupVisibility,
+    pub join_policy: JoinPolicy,
+    pub tags: Vec<u32>,
+    /// Members beyond this wait on the group's waitlist
+    pub max_members: Option<u32>,
+    pub parent_id: Option<u32>,
+    /// Only members of the parent group can join
+    pub requires_parent_membership: bool,
+    pub location: GroupLocation,
+    pub gate: Option<MembershipGate>,
+    /// Members get a soulbound membership token while they are in the group
+    pub mint_membership_tokens: bool,
+    /// NEAR paid into the group's treasury when joining through `join_group`
+    pub join_fee: Option<U128>,
+    /// Nanoseconds a membership lasts before it has to be renewed, `None` never expires
+    pub membership_duration: Option<u64>,
+    pub governance: GovernanceSettings,
     pub matrix_space_id: String,
     pub is_deleted: bool,
+    pub deleted_on: Option<u64>,
     pub updated_on: u64,
     pub created_on: u64,
 }
 
-impl Default for GroupWithMembers {
-    fn default() -> Self {
-        Self {
-            name: Default::default(),
-            description: Default::default(),
-            website: Default::default(),
-            image: Default::default(),
-            banner_image: Default::default(),
-            owner: env::signer_account_id(),
-            created_by: env::signer_account_id(),
-            members: Default::default(),
-            is_deleted: Default::default(),
-            updated_on: Default::default(),
-            created_on: Default::default(),
-            matrix_space_id: Default::default(),
-        }
-    }
-}
-
 #[derive(Clone)]
 #[near(serializers = ["json"])]
 pub struct PostGroup {
@@ -48,10 +74,17 @@ pub struct PostGroup {
     pub image: String,
     pub banner_image: String,
     pub tags: Vec<u32>,
+    pub visibility: Option<GroupVisibility>,
+    pub join_policy: Option<JoinPolicy>,
+    pub max_members: Option<u32>,
+    pub parent_id: Option<u32>,
+    pub requires_parent_membership: Option<bool>,
+    pub location: Option<GroupLocation>,
+    pub mint_membership_tokens: Option<bool>,
 }
 
-impl From<PostGroup> for GroupWithMembers {
-    fn from(group: PostGroup) -> Self {
+impl GroupWithMembers {
+    pub fn new(group_id: u32, group: PostGroup) -> Self {
         Self {
             name: group.name,
             description: group.description,
@@ -60,8 +93,26 @@ impl From<PostGroup> for GroupWithMembers {
             banner_image: group.banner_image,
             owner: env::signer_account_id(),
             created_by: env::signer_account_id(),
-            members: Members::new_with_owner(env::signer_account_id()),
+            members: Members::new_with_owner(group_id, env::signer_account_id()),
+            permissions: Default::default(),
+            succession_policy: Default::default(),
+            visibility: group.visibility.unwrap_or_default(),
+            join_policy: group.join_policy.unwrap_or_default(),
+            tags: normalize_tags(group.tags),
+            max_members: group.max_members,
+            parent_id: group.parent_id,
+            requires_parent_membership: group.requires_parent_membership.unwrap_or(false),
+            location: group
+                .location
+                .map(GroupLocation::normalized)
+                .unwrap_or_default(),
+            gate: None,
+            mint_membership_tokens: group.mint_membership_tokens.unwrap_or(false),
+            join_fee: None,
+            membership_duration: None,
+            governance: Default::default(),
             is_deleted: false,
+            deleted_on: None,
             updated_on: env::block_timestamp(),
             created_on: env::block_timestamp(),
             matrix_space_id: group.matrix_space_id,
@@ -69,8 +120,36 @@ impl From<PostGroup> for GroupWithMembers {
     }
 }
 
+/// Key of the name index: trimmed, single spaced and lowercase.
+pub fn normalize_group_name(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// URL-safe form of a name: lowercase letters and digits separated by single dashes.
+pub fn group_slug(name: &str) -> String {
+    let mut slug = String::new();
+    for c in name.chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+/// Sorted and without duplicates, so the tag index holds every group once per tag.
+fn normalize_tags(mut tags: Vec<u32>) -> Vec<u32> {
+    tags.sort_unstable();
+    tags.dedup();
+    tags
+}
+
 #[derive(Clone, Debug)]
-#[near(serializers = ["json"])]
+#[near(serializers = ["json", "borsh"])]
 pub struct UpdateGroup {
     pub name: Option<String>,
     pub description: Option<String>,
@@ -78,40 +157,86 @@ pub struct UpdateGroup {
     pub image: Option<String>,
     pub banner_image: Option<String>,
     pub tags: Option<Vec<u32>>,
+    pub visibility: Option<GroupVisibility>,
+    pub join_policy: Option<JoinPolicy>,
+    pub requires_parent_membership: Option<bool>,
+    /// Only the owner can move the group to another Matrix space
+    pub matrix_space_id: Option<String>,
+    /// Replaces the whole location, a default location clears it
+    pub location: Option<GroupLocation>,
+    /// Only applies to members joining after the change
+    pub mint_membership_tokens: Option<bool>,
 }
 
 impl GroupWithMembers {
-    pub fn update(&self, group: UpdateGroup) -> Self {
-        Self {
-            name: group.name.unwrap_or_else(|| self.name.clone()),
-            description: group
-                .description
-                .unwrap_or_else(|| self.description.clone()),
-            website: group.website.unwrap_or_else(|| self.website.clone()),
-            image: group.image.unwrap_or_else(|| self.image.clone()),
-            banner_image: group
-                .banner_image
-                .unwrap_or_else(|| self.banner_image.clone()),
-            owner: self.owner.clone(),
-            created_by: self.created_by.clone(),
-            members: self.members.clone(),
-            is_deleted: self.is_deleted,
-            updated_on: env::block_timestamp(),
-            created_on: self.created_on,
-            matrix_space_id: self.matrix_space_id.clone(),
+    pub fn update(&mut self, group: UpdateGroup) {
+        if let Some(name) = group.name {
+            self.name = name;
+        }
+        if let Some(description) = group.description {
+            self.description = description;
+        }
+        if let Some(website) = group.website {
+            self.website = website;
+        }
+        if let Some(image) = group.image {
+            self.image = image;
+        }
+        if let Some(banner_image) = group.banner_image {
+            self.banner_image = banner_image;
         }
+        if let Some(visibility) = group.visibility {
+            self.visibility = visibility;
+        }
+        if let Some(join_policy) = group.join_policy {
+            self.join_policy = join_policy;
+        }
+        if let Some(tags) = group.tags {
+            self.tags = normalize_tags(tags);
+        }
+        if let Some(requires_parent_membership) = group.requires_parent_membership {
+            self.requires_parent_membership = requires_parent_membership;
+        }
+        if let Some(location) = group.location {
+            self.location = location.normalized();
+        }
+        if let Some(matrix_space_id) = group.matrix_space_id {
+            self.matrix_space_id = matrix_space_id;
+        }
+        if let Some(mint_membership_tokens) = group.mint_membership_tokens {
+            self.mint_membership_tokens = mint_membership_tokens;
+        }
+        self.updated_on = env::block_timestamp();
     }
 
-    pub fn set_owner(&mut self, owner: AccountId) -> Self {
-        self.owner = owner.clone();
-        self.members.set_owner(owner);
-        self.clone()
+    pub fn set_owner(&mut self, owner: AccountId, previous_owner_role: ApplicationRole) {
+        self.members
+            .set_owner(owner.clone(), &self.owner, previous_owner_role);
+        self.owner = owner;
+        self.updated_on = env::block_timestamp();
     }
 
-    pub fn delete(&mut self) -> Self {
+    pub fn delete(&mut self) {
         self.is_deleted = true;
+        self.deleted_on = Some(env::block_timestamp());
         self.updated_on = env::block_timestamp();
-        self.clone()
+    }
+
+    pub fn restore(&mut self) {
+        self.is_deleted = false;
+        self.deleted_on = None;
+        self.updated_on = env::block_timestamp();
+    }
+
+    pub fn is_restorable(&self) -> bool {
+        self.deleted_on.is_some_and(|deleted_on| {
+            env::block_timestamp() <= deleted_on + GROUP_DELETION_GRACE_PERIOD
+        })
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.max_members
+            .is_some_and(|max_members| self.members.active_len() >= max_members)
     }
 
     pub fn get_members(&self) -> Vec<AccountId> {
@@ -119,16 +244,64 @@ impl GroupWithMembers {
     }
 
     pub fn remove_member(&mut self, member: AccountId) {
-        self.members.members.remove(&member);
+        self.members.remove(&member);
+    }
+
+    pub fn add_member(
+        &mut self,
+        member: AccountId,
+        role: ApplicationRole,
+        added_by: Option<AccountId>,
+    ) {
+        let mut new_member = Member::new(role, added_by);
+        new_member.expires_on = self
+            .membership_duration
+            .map(|duration| env::block_timestamp().saturating_add(duration));
+        self.members.insert(member, new_member);
+    }
+
+    pub fn set_member_role(&mut self, member: AccountId, role: ApplicationRole) {
+        if let Some(current) = self.members.members.get_mut(&member) {
+            current.role = role;
+        }
     }
 
-    pub fn add_member(&mut self, member: AccountId) {
-        self.members.members.insert(member, ApplicationRole::Member);
+    /// Role of an account in the group, accounts outside of it are treated as guests.
+    pub fn role_of(&self, member: &AccountId) -> ApplicationRole {
+        self.members
+            .role_of(member)
+            .cloned()
+            .unwrap_or(ApplicationRole::Guest)
     }
 
     pub fn is_member(&self, member: AccountId) -> bool {
         self.members.is_member(member)
     }
+
+    pub fn permissions_of(&self, member: &AccountId) -> Vec<Permission> {
+        self.members
+            .role_of(member)
+            .map(|role| self.permissions.for_role(role))
+            .unwrap_or_default()
+    }
+
+    pub fn has_permission(&self, member: &AccountId, permission: Permission) -> bool {
+        self.members
+            .role_of(member)
+            .is_some_and(|role| self.permissions.has(role, permission))
+    }
+
+    /// Whether `member` may give a role to someone or take it away: it has to
+    /// rank below them, and roles holding an owner only permission, like the
+    /// Treasurer, are only handed out by the owner.
+    pub fn can_assign_role(&self, member: &AccountId, role: &ApplicationRole) -> bool {
+        let is_owner_only = self
+            .permissions
+            .for_role(role)
+            .iter()
+            .any(Permission::is_owner_only);
+        self.role_of(member).outranks(role) && (self.owner == *member || !is_owner_only)
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -136,6 +309,7 @@ impl GroupWithMembers {
 pub struct GroupResponse {
     pub id: u32,
     pub name: String,
+    pub slug: String,
     pub description: String,
     pub website: String,
     pub created_by: AccountId,
@@ -143,28 +317,104 @@ pub struct GroupResponse {
     pub matrix_space_id: String,
     pub image: String,
     pub banner_image: String,
+    pub succession_policy: SuccessionPolicy,
+    pub visibility: GroupVisibility,
+    pub join_policy: JoinPolicy,
+    pub tags: Vec<u32>,
+    pub max_members: Option<u32>,
+    pub parent_id: Option<u32>,
+    pub requires_parent_membership: bool,
+    pub location: GroupLocation,
+    pub gate: Option<MembershipGate>,
+    pub mint_membership_tokens: bool,
+    pub join_fee: Option<U128>,
+    pub membership_duration: Option<u64>,
+    pub governance: GovernanceSettings,
     pub is_deleted: bool,
+    pub deleted_on: Option<u64>,
     pub updated_on: u64,
     pub created_on: u64,
     pub members_count: u64,
 }
 
 impl GroupResponse {
-    pub fn new(id: u32, group: GroupWithMembers) -> Self {
+    pub fn new(id: u32, group: &GroupWithMembers) -> Self {
         Self {
             id,
-            name: group.name,
-            description: group.description,
-            website: group.website,
-            created_by: group.created_by,
-            owner: group.owner,
-            matrix_space_id: group.matrix_space_id,
-            image: group.image,
-            banner_image: group.banner_image,
+            name: group.name.clone(),
+            slug: group_slug(&group.name),
+            description: group.description.clone(),
+            website: group.website.clone(),
+            created_by: group.created_by.clone(),
+            owner: group.owner.clone(),
+            matrix_space_id: group.matrix_space_id.clone(),
+            image: group.image.clone(),
+            banner_image: group.banner_image.clone(),
+            succession_policy: group.succession_policy.clone(),
+            visibility: group.visibility.clone(),
+            join_policy: group.join_policy.clone(),
+            tags: group.tags.clone(),
+            max_members: group.max_members,
+            parent_id: group.parent_id,
+            requires_parent_membership: group.requires_parent_membership,
+            location: group.location.clone(),
+            gate: group.gate.clone(),
+            mint_membership_tokens: group.mint_membership_tokens,
+            join_fee: group.join_fee,
+            membership_duration: group.membership_duration,
+            governance: group.governance.clone(),
             is_deleted: group.is_deleted,
+            deleted_on: group.deleted_on,
             updated_on: group.updated_on,
             created_on: group.created_on,
-            members_count: group.members.members.len() as u64,
+            members_count: group.members.active_len() as u64,
         }
     }
 }
+
+#[derive(Clone, Debug, Default)]
+#[near(serializers = ["json"])]
+pub enum GroupSort {
+    #[default]
+    CreatedOn,
+    UpdatedOn,
+    MembersCount,
+}
+
+#[derive(Clone, Debug, Default)]
+#[near(serializers = ["json"])]
+pub struct GroupFilter {
+    pub owner: Option<AccountId>,
+    pub tag: Option<u32>,
+    /// Deleted groups are left out unless set
+    pub is_deleted: Option<bool>,
+    /// Only public groups are listed unless set
+    pub visibility: Option<GroupVisibility>,
+}
+
+impl GroupFilter {
+    pub fn matches(&self, group: &GroupWithMembers) -> bool {
+        self.owner
+            .as_ref()
+            .is_none_or(|owner| group.owner == *owner)
+            && self.tag.is_none_or(|tag| group.tags.contains(&tag))
+            && group.is_deleted == self.is_deleted.unwrap_or(false)
+            && group.visibility == self.visibility.clone().unwrap_or_default()
+    }
+}
+
+/// Position after the last group of a page: its sort key and id.
+#[derive(Clone, Debug, PartialEq)]
+#[near(serializers = ["json"])]
+pub struct GroupCursor {
+    pub key: u64,
+    pub id: u32,
+}
+
+#[derive(Clone, Debug)]
+#[near(serializers = ["json"])]
+pub struct GroupPage {
+    pub groups: Vec<GroupResponse>,
+    /// Pass back to get the next page, `None` on the last page
+    pub next_cursor: Option<GroupCursor>,
+}
@@ -0,0 +1,81 @@
+use super::application_role::ApplicationRole;
+use near_sdk::{env, near, AccountId};
+
+#[derive(Clone, Debug)]
+#[near(serializers = ["json", "borsh"])]
+pub struct GroupInvite {
+    /// Hex encoded sha256 of the code, the code itself is never stored
+    pub id: String,
+    pub group_id: u32,
+    pub created_by: AccountId,
+    pub label: String, // name of the invite campaign
+    pub role: Option<ApplicationRole>,
+    pub max_uses: u32,
+    pub uses: u32,
+    pub expires_on: u64,
+    pub is_revoked: bool,
+    pub redemptions: Vec<InviteRedemption>,
+    pub created_on: u64,
+}
+
+#[derive(Clone, Debug)]
+#[near(serializers = ["json", "borsh"])]
+pub struct InviteRedemption {
+    pub account_id: AccountId,
+    pub redeemed_on: u64,
+}
+
+#[derive(Clone, Debug)]
+#[near(serializers = ["json"])]
+pub struct PostGroupInvite {
+    pub expires_on: u64,
+    pub max_uses: u32,
+    pub role: Option<ApplicationRole>,
+    pub label: Option<String>,
+}
+
+impl GroupInvite {
+    pub fn new(id: String, group_id: u32, invite: PostGroupInvite) -> Self {
+        Self {
+            id,
+            group_id,
+            created_by: env::predecessor_account_id(),
+            label: invite.label.unwrap_or_default(),
+            role: invite.role,
+            max_uses: invite.max_uses,
+            uses: 0,
+            expires_on: invite.expires_on,
+            is_revoked: false,
+            redemptions: vec![],
+            created_on: env::block_timestamp(),
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        env::block_timestamp() >= self.expires_on
+    }
+
+    pub fn is_used_up(&self) -> bool {
+        self.uses >= self.max_uses
+    }
+
+    pub fn is_active(&self) -> bool {
+        !self.is_revoked && !self.is_expired() && !self.is_used_up()
+    }
+
+    pub fn redeem(&mut self, account_id: AccountId) {
+        self.uses += 1;
+        self.redemptions.push(InviteRedemption {
+            account_id,
+            redeemed_on: env::block_timestamp(),
+        });
+    }
+}
+
+/// Id an invite is stored and managed under, so codes can't be read back from state.
+pub fn invite_id(code: &str) -> String {
+    env::sha256(code.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
@@ -0,0 +1,24 @@
+use near_sdk::{env, json_types::U128, near, AccountId};
+
+#[derive(Clone, Debug)]
+#[near(serializers = ["json", "borsh"])]
+pub struct JoinRequest {
+    pub group_id: u32,
+    pub account_id: AccountId,
+    pub message: String,
+    pub requested_on: u64,
+    /// Join fee held until the request is approved, refunded otherwise
+    pub deposit: U128,
+}
+
+impl JoinRequest {
+    pub fn new(group_id: u32, account_id: AccountId, message: String, deposit: u128) -> Self {
+        Self {
+            group_id,
+            account_id,
+            message,
+            requested_on: env::block_timestamp(),
+            deposit: U128(deposit),
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use near_sdk::near;
+
+/// Longest geohash prefix kept in the location index, about 1.2km by 0.6km.
+pub const GEOHASH_INDEX_PRECISION: usize = 6;
+const GEOHASH_ALPHABET: &str = "0123456789bcdefghjkmnpqrstuvwxyz";
+const MAX_GEOHASH_LENGTH: usize = 12;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[near(serializers = ["json", "borsh"])]
+pub struct GroupLocation {
+    pub city: String,
+    pub state_or_province: String,
+    pub country: String,
+    pub geohash: Option<String>,
+}
+
+impl GroupLocation {
+    pub fn is_valid(&self) -> bool {
+        self.geohash
+            .as_ref()
+            .is_none_or(|geohash| is_geohash(&geohash.to_lowercase()))
+    }
+
+    pub fn normalized(mut self) -> Self {
+        self.geohash = self.geohash.map(|geohash| geohash.to_lowercase());
+        self
+    }
+
+    /// Keys the group is listed under in the location index.
+    pub fn index_keys(&self) -> Vec<String> {
+        let mut keys = vec![];
+        let country = normalize_place(&self.country);
+        if !country.is_empty() {
+            let region = normalize_place(&self.state_or_province);
+            if !region.is_empty() {
+                keys.push(region_key(&country, &region));
+            }
+            keys.push(country_key(&country));
+        }
+        if let Some(geohash) = &self.geohash {
+            for length in 1..=geohash.len().min(GEOHASH_INDEX_PRECISION) {
+                keys.push(geohash_key(&geohash[..length]));
+            }
+        }
+        keys
+    }
+}
+
+pub fn is_geohash(geohash: &str) -> bool {
+    !geohash.is_empty()
+        && geohash.len() <= MAX_GEOHASH_LENGTH
+        && geohash.chars().all(|c| GEOHASH_ALPHABET.contains(c))
+}
+
+pub fn normalize_place(place: &str) -> String {
+    place
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+pub fn country_key(country: &str) -> String {
+    format!("country:{}", country)
+}
+
+pub fn region_key(country: &str, region: &str) -> String {
+    format!("region:{}/{}", country, region)
+}
+
+pub fn geohash_key(prefix: &str) -> String {
+    format!("geohash:{}", prefix)
+}
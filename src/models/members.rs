@@ -1,32 +1,240 @@
-use near_sdk::{env, near, AccountId};
-use std::collections::hash_map::Entry;
-use std::collections::HashMap;
+use near_sdk::{env, near, store::TreeMap, AccountId};
 
 use super::application_role::ApplicationRole;
 
+#[derive(Clone, Debug)]
+#[near(serializers = ["json", "borsh"])]
+pub struct Member {
+    pub role: ApplicationRole,
+    pub joined_on: u64,
+    pub added_by: Option<AccountId>, // who invited or approved the member
+    /// Members of subscription groups lose access after this, unless they renew
+    pub expires_on: Option<u64>,
+}
+
+impl Member {
+    pub fn new(role: ApplicationRole, added_by: Option<AccountId>) -> Self {
+        Self {
+            role,
+            joined_on: env::block_timestamp(),
+            added_by,
+            expires_on: None,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_on
+            .is_some_and(|expires_on| expires_on <= env::block_timestamp())
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[near(serializers = ["json"])]
+pub struct MemberFilter {
+    pub role: Option<ApplicationRole>,
+    /// Members of a deleted group are left out unless set
+    pub include_deleted: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default)]
+#[near(serializers = ["json"])]
+pub enum MemberSort {
+    JoinedOn,
+    #[default]
+    AccountId,
+}
+
+#[derive(Clone, Debug)]
+#[near(serializers = ["json"])]
+pub struct MemberResponse {
+    pub account_id: AccountId,
+    pub role: ApplicationRole,
+    pub joined_on: u64,
+    pub added_by: Option<AccountId>,
+    pub expires_on: Option<u64>,
+}
+
+#[derive(Clone, Debug)]
+#[near(serializers = ["json"])]
+pub struct MatrixMember {
+    pub account_id: AccountId,
+    pub matrix_user_id: String,
+}
+
+impl MemberResponse {
+    pub fn new(account_id: AccountId, member: &Member) -> Self {
+        Self {
+            account_id,
+            role: member.role.clone(),
+            joined_on: member.joined_on,
+            added_by: member.added_by.clone(),
+            expires_on: member.expires_on,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 #[near(serializers = ["json", "borsh"])]
+pub enum SuccessionPolicy {
+    /// The owner has to transfer ownership before leaving
+    #[default]
+    Disabled,
+    /// The admin that joined first inherits ownership
+    LongestStandingAdmin,
+    /// The highest ranked member inherits ownership, the one that joined first on a tie
+    HighestRole,
+}
+
+/// Members of a single group, kept in their own storage collection keyed by
+/// the group id and account id so a group only carries the collection's length.
+/// Members are ordered by account id, `by_joined_on` orders them by join time
+/// so pages can be read without sorting the whole group, and `by_expires_on`
+/// orders expiring memberships so expired ones are found without a full scan.
+#[derive(Debug)]
+#[near(serializers = ["borsh"])]
 pub struct Members {
-    pub members: HashMap<AccountId, ApplicationRole>,
+    pub members: TreeMap<AccountId, Member>,
+    by_joined_on: TreeMap<(u64, AccountId), ()>,
+    by_expires_on: TreeMap<(u64, AccountId), ()>,
 }
 
 impl Members {
-    pub fn new_with_owner(owner: AccountId) -> Self {
-        let mut members = HashMap::new();
-        members.insert(owner, ApplicationRole::Owner);
-        Self { members }
+    pub fn new(group_id: u32) -> Self {
+        Self {
+            members: TreeMap::new(Self::storage_prefix(group_id)),
+            by_joined_on: TreeMap::new([b"e".as_slice(), &group_id.to_le_bytes()].concat()),
+            by_expires_on: TreeMap::new([b"v".as_slice(), &group_id.to_le_bytes()].concat()),
+        }
+    }
+
+    pub fn new_with_owner(group_id: u32, owner: AccountId) -> Self {
+        let mut members = Self::new(group_id);
+        members.insert(owner, Member::new(ApplicationRole::Owner, None));
+        members
+    }
+
+    pub fn storage_prefix(group_id: u32) -> Vec<u8> {
+        [b"m".as_slice(), &group_id.to_le_bytes()].concat()
+    }
+
+    /// Adds or replaces a member, keeping the indexes in sync.
+    pub fn insert(&mut self, account_id: AccountId, member: Member) {
+        self.remove(&account_id);
+        self.by_joined_on
+            .insert((member.joined_on, account_id.clone()), ());
+        if let Some(expires_on) = member.expires_on {
+            self.by_expires_on
+                .insert((expires_on, account_id.clone()), ());
+        }
+        self.members.insert(account_id, member);
+    }
+
+    pub fn remove(&mut self, account_id: &AccountId) -> Option<Member> {
+        let member = self.members.remove(account_id)?;
+        self.by_joined_on
+            .remove(&(member.joined_on, account_id.clone()));
+        if let Some(expires_on) = member.expires_on {
+            self.by_expires_on.remove(&(expires_on, account_id.clone()));
+        }
+        Some(member)
+    }
+
+    pub fn clear(&mut self) {
+        self.members.clear();
+        self.by_joined_on.clear();
+        self.by_expires_on.clear();
+    }
+
+    /// Changes when a membership expires, `None` makes it permanent.
+    pub fn set_expires_on(&mut self, account_id: &AccountId, expires_on: Option<u64>) {
+        let Some(member) = self.members.get_mut(account_id) else {
+            return;
+        };
+        if let Some(previous) = member.expires_on {
+            self.by_expires_on.remove(&(previous, account_id.clone()));
+        }
+        member.expires_on = expires_on;
+        if let Some(expires_on) = expires_on {
+            self.by_expires_on
+                .insert((expires_on, account_id.clone()), ());
+        }
+    }
+
+    /// Members with an expiry, soonest first, expired ones included.
+    pub fn by_expiry(&self) -> impl Iterator<Item = (&AccountId, &Member)> {
+        self.by_expires_on
+            .keys()
+            .filter_map(|(_, account_id)| self.members.get_key_value(account_id))
+    }
+
+    /// Number of members whose membership hasn't expired.
+    pub fn active_len(&self) -> u32 {
+        let now = env::block_timestamp();
+        let expired = self
+            .by_expires_on
+            .keys()
+            .take_while(|(expires_on, _)| *expires_on <= now)
+            .count() as u32;
+        self.len() - expired
+    }
+
+    /// Members whose membership hasn't expired, ordered by account id.
+    pub fn active(&self) -> impl Iterator<Item = (&AccountId, &Member)> {
+        self.members
+            .iter()
+            .filter(|(_, member)| !member.is_expired())
+    }
+
+    /// Every member in the order of `sort`, reversed when `descending`.
+    pub fn sorted(
+        &self,
+        sort: &MemberSort,
+        descending: bool,
+    ) -> Box<dyn Iterator<Item = (&AccountId, &Member)> + '_> {
+        match (sort, descending) {
+            (MemberSort::AccountId, false) => Box::new(self.members.iter()),
+            (MemberSort::AccountId, true) => Box::new(self.members.iter().rev()),
+            (MemberSort::JoinedOn, false) => Box::new(
+                self.by_joined_on
+                    .keys()
+                    .filter_map(|(_, account_id)| self.members.get_key_value(account_id)),
+            ),
+            (MemberSort::JoinedOn, true) => Box::new(
+                self.by_joined_on
+                    .keys()
+                    .rev()
+                    .filter_map(|(_, account_id)| self.members.get_key_value(account_id)),
+            ),
+        }
     }
 
-    pub fn set_owner(&mut self, new_owner: AccountId) -> bool {
+    /// Makes `new_owner` the owner, `previous_owner` is demoted to `previous_owner_role`.
+    pub fn set_owner(
+        &mut self,
+        new_owner: AccountId,
+        previous_owner: &AccountId,
+        previous_owner_role: ApplicationRole,
+    ) -> bool {
+        if *previous_owner != new_owner {
+            if let Some(member) = self.members.get_mut(previous_owner) {
+                member.role = previous_owner_role.clone();
+                env::log_str(&format!(
+                    "Previous owner {} demoted to {}",
+                    previous_owner, previous_owner_role
+                ));
+            }
+        }
+
         // Set new owner
-        match self.members.entry(new_owner.clone()) {
-            Entry::Occupied(mut entry) => {
-                *entry.get_mut() = ApplicationRole::Owner;
+        self.set_expires_on(&new_owner, None);
+        match self.members.get_mut(&new_owner) {
+            Some(member) => {
+                member.role = ApplicationRole::Owner;
                 env::log_str(&format!("Existing member {} promoted to owner", new_owner));
                 true
             }
-            Entry::Vacant(entry) => {
-                entry.insert(ApplicationRole::Owner);
+            None => {
+                self.insert(new_owner.clone(), Member::new(ApplicationRole::Owner, None));
                 env::log_str(&format!("New owner {} added", new_owner));
                 true
             }
@@ -36,4 +244,55 @@ impl Members {
     pub fn is_member(&self, member: AccountId) -> bool {
         self.members.contains_key(&member)
     }
+
+    /// Like `is_member`, but expired members no longer count.
+    pub fn is_active_member(&self, member: &AccountId) -> bool {
+        self.members
+            .get(member)
+            .is_some_and(|member| !member.is_expired())
+    }
+
+    /// An active member, expired members are left out.
+    pub fn get_active(&self, member: &AccountId) -> Option<&Member> {
+        self.members
+            .get(member)
+            .filter(|member| !member.is_expired())
+    }
+
+    /// Role of an active member, expired members have none.
+    pub fn role_of(&self, member: &AccountId) -> Option<&ApplicationRole> {
+        self.get_active(member).map(|member| &member.role)
+    }
+
+    pub fn len(&self) -> u32 {
+        self.members.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Picks who inherits ownership from `owner` according to `policy`.
+    pub fn successor(&self, owner: &AccountId, policy: &SuccessionPolicy) -> Option<AccountId> {
+        let candidates = self.members.iter().filter(|(account_id, member)| {
+            *account_id != owner
+                && !member.is_expired()
+                && member.role.outranks(&ApplicationRole::Guest)
+        });
+
+        match policy {
+            SuccessionPolicy::Disabled => None,
+            SuccessionPolicy::LongestStandingAdmin => candidates
+                .filter(|(_, member)| member.role == ApplicationRole::Admin)
+                .min_by_key(|(account_id, member)| (member.joined_on, (*account_id).clone())),
+            SuccessionPolicy::HighestRole => candidates.min_by_key(|(account_id, member)| {
+                (
+                    std::cmp::Reverse(member.role.rank()),
+                    member.joined_on,
+                    (*account_id).clone(),
+                )
+            }),
+        }
+        .map(|(account_id, _)| account_id.clone())
+    }
 }
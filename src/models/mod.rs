@@ -1,5 +1,18 @@
 pub mod application_role;
+pub mod bans;
+pub mod gates;
 pub mod groups;
+pub mod invites;
+pub mod join_requests;
+pub mod location;
 pub mod members;
+pub mod ownership_transfer;
+pub mod permissions;
+pub mod polls;
 pub mod profile;
+pub mod proposals;
 pub mod rewards;
+pub mod sbt;
+pub mod sorting;
+pub mod treasury;
+pub mod waitlist;
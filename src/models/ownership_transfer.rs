@@ -0,0 +1,12 @@
+use super::application_role::ApplicationRole;
+use near_sdk::{near, AccountId};
+
+#[derive(Clone, Debug)]
+#[near(serializers = ["json", "borsh"])]
+pub struct OwnershipTransfer {
+    pub group_id: u32,
+    pub from: AccountId,
+    pub to: AccountId,
+    pub demote_to: ApplicationRole, // role the current owner keeps once the transfer is accepted
+    pub created_on: u64,
+}
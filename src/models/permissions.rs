@@ -0,0 +1,83 @@
+use near_sdk::near;
+use std::collections::HashMap;
+
+use super::application_role::ApplicationRole;
+
+#[near(serializers=["json", "borsh"])]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Permission {
+    EditMetadata,
+    ManageMembers,
+    ModerateContent,
+    ManageRoles,
+    DeleteGroup,
+    ManageTreasury,
+    ManagePolls,
+    CreateSubgroups,
+}
+
+impl Permission {
+    pub const ALL: [Permission; 8] = [
+        Permission::EditMetadata,
+        Permission::ManageMembers,
+        Permission::ModerateContent,
+        Permission::ManageRoles,
+        Permission::DeleteGroup,
+        Permission::ManageTreasury,
+        Permission::ManagePolls,
+        Permission::CreateSubgroups,
+    ];
+
+    /// Permissions that destroy a group or move its funds, only the owner hands them out.
+    pub fn is_owner_only(&self) -> bool {
+        matches!(self, Permission::DeleteGroup | Permission::ManageTreasury)
+    }
+}
+
+/// Per group overrides of the default permission matrix, roles without an
+/// override fall back to `ApplicationRole::default_permissions`.
+#[derive(Clone, Debug, Default)]
+#[near(serializers = ["json", "borsh"])]
+pub struct GroupPermissions {
+    pub overrides: HashMap<ApplicationRole, Vec<Permission>>,
+}
+
+impl GroupPermissions {
+    pub fn for_role(&self, role: &ApplicationRole) -> Vec<Permission> {
+        if !role.permissions_can_be_overridden() {
+            return role.default_permissions();
+        }
+        self.overrides
+            .get(role)
+            .cloned()
+            .unwrap_or_else(|| role.default_permissions())
+    }
+
+    pub fn has(&self, role: &ApplicationRole, permission: Permission) -> bool {
+        self.for_role(role).contains(&permission)
+    }
+
+    pub fn set(&mut self, role: ApplicationRole, permissions: Option<Vec<Permission>>) {
+        match permissions {
+            Some(mut permissions) => {
+                permissions.sort();
+                permissions.dedup();
+                self.overrides.insert(role, permissions);
+            }
+            None => {
+                self.overrides.remove(&role);
+            }
+        }
+    }
+
+    pub fn matrix(&self) -> HashMap<ApplicationRole, Vec<Permission>> {
+        use ApplicationRole::*;
+        [Owner, Admin, Moderator, Treasurer, Member, Guest, Banned]
+            .into_iter()
+            .map(|role| {
+                let permissions = self.for_role(&role);
+                (role, permissions)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,139 @@
+use near_sdk::{env, near, store::IterableMap, AccountId};
+
+pub const MAX_POLL_OPTIONS: usize = 20;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[near(serializers = ["json", "borsh"])]
+pub enum ResultsVisibility {
+    /// Results can be seen while the poll is open
+    #[default]
+    Live,
+    /// The poll views hide the results until the poll closes. This only affects
+    /// display: the counts and votes are in contract state, which anyone can read
+    /// with `view_state`.
+    AfterDeadline,
+}
+
+/// A question put to the members of a group, answered by picking one or, when
+/// `allow_multiple` is set, several of its options until `ends_on`.
+#[derive(Debug)]
+#[near(serializers = ["borsh"])]
+pub struct Poll {
+    pub group_id: u32,
+    pub created_by: AccountId,
+    pub question: String,
+    pub options: Vec<String>,
+    pub allow_multiple: bool,
+    pub results_visibility: ResultsVisibility,
+    /// Chosen option indexes of every voter
+    pub votes: IterableMap<AccountId, Vec<u32>>,
+    /// Votes per option
+    pub counts: Vec<u32>,
+    pub ends_on: u64,
+    pub created_on: u64,
+}
+
+impl Poll {
+    pub fn new(id: u64, group_id: u32, created_by: AccountId, post_poll: PostPoll) -> Self {
+        Self {
+            group_id,
+            created_by,
+            question: post_poll.question,
+            counts: vec![0; post_poll.options.len()],
+            options: post_poll.options,
+            allow_multiple: post_poll.allow_multiple.unwrap_or(false),
+            results_visibility: post_poll.results_visibility.unwrap_or_default(),
+            votes: IterableMap::new([b"zw".as_slice(), &id.to_le_bytes()].concat()),
+            ends_on: post_poll.ends_on,
+            created_on: env::block_timestamp(),
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        env::block_timestamp() < self.ends_on
+    }
+
+    /// A vote picks distinct, existing options, exactly one unless several are allowed.
+    pub fn is_valid_vote(&self, options: &[u32]) -> bool {
+        let mut sorted = options.to_vec();
+        sorted.sort_unstable();
+        sorted.dedup();
+        !options.is_empty()
+            && sorted.len() == options.len()
+            && (self.allow_multiple || options.len() == 1)
+            && options
+                .iter()
+                .all(|option| (*option as usize) < self.options.len())
+    }
+
+    /// Records a vote, its options must have been checked with `is_valid_vote`.
+    pub fn cast_vote(&mut self, account_id: AccountId, options: Vec<u32>) {
+        for option in &options {
+            self.counts[*option as usize] += 1;
+        }
+        self.votes.insert(account_id, options);
+    }
+
+    /// Votes per option, `None` while the results are hidden.
+    pub fn results(&self) -> Option<Vec<u32>> {
+        if self.results_visibility == ResultsVisibility::AfterDeadline && self.is_open() {
+            return None;
+        }
+        Some(self.counts.clone())
+    }
+}
+
+#[derive(Clone)]
+#[near(serializers = ["json"])]
+pub struct PostPoll {
+    pub question: String,
+    pub options: Vec<String>,
+    pub allow_multiple: Option<bool>,
+    pub results_visibility: Option<ResultsVisibility>,
+    /// Timestamp in nanoseconds after which votes are no longer accepted
+    pub ends_on: u64,
+}
+
+impl PostPoll {
+    pub fn is_valid(&self) -> bool {
+        !self.question.trim().is_empty()
+            && (2..=MAX_POLL_OPTIONS).contains(&self.options.len())
+            && self.ends_on > env::block_timestamp()
+    }
+}
+
+#[derive(Clone, Debug)]
+#[near(serializers = ["json"])]
+pub struct PollResponse {
+    pub id: u64,
+    pub group_id: u32,
+    pub created_by: AccountId,
+    pub question: String,
+    pub options: Vec<String>,
+    pub allow_multiple: bool,
+    pub results_visibility: ResultsVisibility,
+    pub voters_count: u32,
+    pub results: Option<Vec<u32>>,
+    pub is_open: bool,
+    pub ends_on: u64,
+    pub created_on: u64,
+}
+
+impl PollResponse {
+    pub fn new(id: u64, poll: &Poll) -> Self {
+        Self {
+            id,
+            group_id: poll.group_id,
+            created_by: poll.created_by.clone(),
+            question: poll.question.clone(),
+            options: poll.options.clone(),
+            allow_multiple: poll.allow_multiple,
+            results_visibility: poll.results_visibility.clone(),
+            voters_count: poll.votes.len(),
+            results: poll.results(),
+            is_open: poll.is_open(),
+            ends_on: poll.ends_on,
+            created_on: poll.created_on,
+        }
+    }
+}
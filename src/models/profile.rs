@@ -17,6 +17,7 @@ pub struct Profile {
     pub profile_image: String, //url to the IPFS image
     pub banner_image: String,  //url to the IPFS image
     pub website: String,
+    pub matrix_user_id: Option<String>, // e.g. @alice:matrix.org
     pub application_role: ApplicationRole,
     pub joined_groups: Vec<u32>,
     pub skills: Vec<u32>,
@@ -80,6 +81,7 @@ impl Profile {
             interests: profile.interests.unwrap_or_else(|| self.interests.clone()),
             causes: profile.causes.unwrap_or_else(|| self.causes.clone()),
             website: profile.website.unwrap_or_else(|| self.website.clone()),
+            matrix_user_id: self.matrix_user_id.clone(),
             extra: profile.extra.unwrap_or_else(|| self.extra.clone()),
             updated_on: env::block_timestamp(),
             created_on: self.created_on,
@@ -155,6 +157,7 @@ impl From<PostProfile> for Profile {
             interests: vec![],
             causes: vec![],
             website: "".to_string(),
+            matrix_user_id: None,
             extra: profile.extra,
             updated_on: env::block_timestamp(),
             created_on: env::block_timestamp(),
@@ -196,6 +199,7 @@ pub struct ProfileResponse {
     pub interests: Vec<u32>,
     pub causes: Vec<u32>,
     pub website: String,
+    pub matrix_user_id: Option<String>,
     pub extra: String,
     pub updated_on: u64,
     pub created_on: u64,
@@ -211,6 +215,7 @@ impl ProfileResponse {
             city: profile.city,
             country: profile.country,
             website: profile.website,
+            matrix_user_id: profile.matrix_user_id,
             skills: profile.skills,
             interests: profile.interests,
             causes: profile.causes,
@@ -228,3 +233,20 @@ impl ProfileResponse {
         }
     }
 }
+
+/// Checks the `@localpart:server` shape of a Matrix user id.
+pub fn is_matrix_user_id(matrix_user_id: &str) -> bool {
+    let Some((localpart, server)) = matrix_user_id
+        .strip_prefix('@')
+        .and_then(|id| id.split_once(':'))
+    else {
+        return false;
+    };
+    matrix_user_id.len() <= 255
+        && !localpart.is_empty()
+        && localpart
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || "._=-/+".contains(c))
+        && !server.is_empty()
+        && !server.chars().any(char::is_whitespace)
+}
@@ -0,0 +1,232 @@
+use near_sdk::{env, json_types::U128, near, store::IterableMap, AccountId};
+use std::collections::HashMap;
+
+use super::application_role::ApplicationRole;
+use super::groups::UpdateGroup;
+
+/// Default time in nanoseconds a proposal stays open for votes.
+pub const DEFAULT_VOTING_PERIOD: u64 = 7 * 24 * 60 * 60 * 1_000_000_000;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[near(serializers = ["json", "borsh"])]
+pub enum VotingWeight {
+    /// Every active member has a single vote
+    #[default]
+    OneMemberOneVote,
+    /// Votes weigh by the voter's role, roles missing from `weights` get a single vote.
+    /// Guests and banned members never vote, whatever their weight.
+    ByRole {
+        weights: HashMap<ApplicationRole, u32>,
+    },
+}
+
+impl VotingWeight {
+    pub fn of(&self, role: &ApplicationRole) -> u64 {
+        if matches!(role, ApplicationRole::Guest | ApplicationRole::Banned) {
+            return 0;
+        }
+        match self {
+            VotingWeight::OneMemberOneVote => 1,
+            VotingWeight::ByRole { weights } => weights.get(role).copied().unwrap_or(1) as u64,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[near(serializers = ["json", "borsh"])]
+pub struct GovernanceSettings {
+    /// Share of the total voting weight, in percent, that has to vote
+    pub quorum_percent: u8,
+    /// A proposal passes when more than this share of the yes and no weight, in percent, is yes
+    pub threshold_percent: u8,
+    pub voting_period: u64,
+    pub voting_weight: VotingWeight,
+}
+
+impl Default for GovernanceSettings {
+    fn default() -> Self {
+        Self {
+            quorum_percent: 50,
+            threshold_percent: 50,
+            voting_period: DEFAULT_VOTING_PERIOD,
+            voting_weight: VotingWeight::default(),
+        }
+    }
+}
+
+impl GovernanceSettings {
+    pub fn is_valid(&self) -> bool {
+        self.quorum_percent <= 100 && self.threshold_percent < 100 && self.voting_period > 0
+    }
+}
+
+/// What happens when a proposal passes, `Text` only records the decision.
+#[derive(Clone, Debug)]
+#[near(serializers = ["json", "borsh"])]
+pub enum ProposalAction {
+    Text,
+    UpdateGroup {
+        update: Box<UpdateGroup>,
+    },
+    ChangeRole {
+        account_id: AccountId,
+        role: ApplicationRole,
+    },
+    TreasurySpend {
+        receiver_id: AccountId,
+        amount: U128,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[near(serializers = ["json", "borsh"])]
+pub enum ProposalStatus {
+    Active,
+    /// Passed, there was nothing to execute
+    Passed,
+    /// Passed and its action was carried out
+    Executed,
+    Rejected,
+    /// Passed, but its action could no longer be carried out
+    Failed,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[near(serializers = ["json", "borsh"])]
+pub enum Vote {
+    Yes,
+    No,
+    Abstain,
+}
+
+#[derive(Clone, Debug)]
+#[near(serializers = ["json", "borsh"])]
+pub struct CastVote {
+    pub vote: Vote,
+    pub weight: u64,
+}
+
+/// Votes are kept in their own collection, keyed by voter, next to running totals.
+#[derive(Debug)]
+#[near(serializers = ["borsh"])]
+pub struct Proposal {
+    pub group_id: u32,
+    pub proposer: AccountId,
+    pub title: String,
+    pub description: String,
+    pub action: ProposalAction,
+    pub status: ProposalStatus,
+    /// Settings of the group when the proposal was created
+    pub settings: GovernanceSettings,
+    /// Voting weight of all active members when the proposal was created
+    pub total_weight: u64,
+    pub votes: IterableMap<AccountId, CastVote>,
+    pub tally: ProposalTally,
+    pub created_on: u64,
+    pub voting_ends_on: u64,
+}
+
+impl Proposal {
+    pub fn new(
+        id: u64,
+        group_id: u32,
+        proposer: AccountId,
+        post_proposal: PostProposal,
+        settings: GovernanceSettings,
+        total_weight: u64,
+    ) -> Self {
+        Self {
+            group_id,
+            proposer,
+            title: post_proposal.title,
+            description: post_proposal.description,
+            action: post_proposal.action,
+            status: ProposalStatus::Active,
+            voting_ends_on: env::block_timestamp().saturating_add(settings.voting_period),
+            settings,
+            total_weight,
+            votes: IterableMap::new([b"zv".as_slice(), &id.to_le_bytes()].concat()),
+            tally: ProposalTally::default(),
+            created_on: env::block_timestamp(),
+        }
+    }
+
+    pub fn is_voting_open(&self) -> bool {
+        self.status == ProposalStatus::Active && env::block_timestamp() < self.voting_ends_on
+    }
+
+    /// Records a vote and adds its weight to the tally.
+    pub fn cast_vote(&mut self, account_id: AccountId, cast_vote: CastVote) {
+        match cast_vote.vote {
+            Vote::Yes => self.tally.yes += cast_vote.weight,
+            Vote::No => self.tally.no += cast_vote.weight,
+            Vote::Abstain => self.tally.abstain += cast_vote.weight,
+        }
+        self.votes.insert(account_id, cast_vote);
+    }
+
+    /// Whether the votes reach the quorum and the threshold, abstentions only count towards the quorum.
+    pub fn has_passed(&self) -> bool {
+        let tally = &self.tally;
+        let voted = (tally.yes + tally.no + tally.abstain) as u128;
+        let quorum_reached =
+            voted * 100 >= self.total_weight as u128 * self.settings.quorum_percent as u128;
+        let threshold_reached = tally.yes as u128 * 100
+            > (tally.yes + tally.no) as u128 * self.settings.threshold_percent as u128;
+        quorum_reached && threshold_reached
+    }
+}
+
+#[derive(Clone)]
+#[near(serializers = ["json"])]
+pub struct PostProposal {
+    pub title: String,
+    pub description: String,
+    pub action: ProposalAction,
+}
+
+#[derive(Clone, Debug, Default)]
+#[near(serializers = ["json", "borsh"])]
+pub struct ProposalTally {
+    pub yes: u64,
+    pub no: u64,
+    pub abstain: u64,
+}
+
+#[derive(Clone, Debug)]
+#[near(serializers = ["json"])]
+pub struct ProposalResponse {
+    pub id: u64,
+    pub group_id: u32,
+    pub proposer: AccountId,
+    pub title: String,
+    pub description: String,
+    pub action: ProposalAction,
+    pub status: ProposalStatus,
+    pub settings: GovernanceSettings,
+    pub total_weight: u64,
+    pub tally: ProposalTally,
+    pub voters_count: u32,
+    pub created_on: u64,
+    pub voting_ends_on: u64,
+}
+
+impl ProposalResponse {
+    pub fn new(id: u64, proposal: &Proposal) -> Self {
+        Self {
+            id,
+            group_id: proposal.group_id,
+            proposer: proposal.proposer.clone(),
+            title: proposal.title.clone(),
+            description: proposal.description.clone(),
+            action: proposal.action.clone(),
+            status: proposal.status.clone(),
+            settings: proposal.settings.clone(),
+            total_weight: proposal.total_weight,
+            tally: proposal.tally.clone(),
+            voters_count: proposal.votes.len(),
+            created_on: proposal.created_on,
+            voting_ends_on: proposal.voting_ends_on,
+        }
+    }
+}
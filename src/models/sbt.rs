@@ -0,0 +1,86 @@
+use near_sdk::{env, json_types::Base64VecU8, near, serde_json, AccountId};
+
+pub const SBT_STANDARD: &str = "nep393";
+pub const SBT_STANDARD_VERSION: &str = "1.0.0";
+pub const SBT_METADATA_SPEC: &str = "sbt-1.0.0";
+
+#[derive(Clone, Debug)]
+#[near(serializers = ["json"])]
+pub struct SbtContractMetadata {
+    pub spec: String,
+    pub name: String,
+    pub symbol: String,
+    pub icon: Option<String>,
+    pub base_uri: Option<String>,
+    pub reference: Option<String>,
+    pub reference_hash: Option<Base64VecU8>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[near(serializers = ["json", "borsh"])]
+pub struct SbtTokenMetadata {
+    /// The group id plus one, NEP-393 classes start at 1
+    pub class: u64,
+    pub issued_at: Option<u64>,
+    pub expires_at: Option<u64>,
+    pub reference: Option<String>,
+    pub reference_hash: Option<Base64VecU8>,
+}
+
+/// Non-transferable token proving membership of a group.
+#[derive(Clone, Debug)]
+#[near(serializers = ["json", "borsh"])]
+pub struct MembershipToken {
+    pub owner: AccountId,
+    pub metadata: SbtTokenMetadata,
+}
+
+impl MembershipToken {
+    /// `expires_on` is the membership expiry in nanoseconds, tokens keep milliseconds.
+    pub fn new(owner: AccountId, group_id: u32, expires_on: Option<u64>) -> Self {
+        Self {
+            owner,
+            metadata: SbtTokenMetadata {
+                class: class_of(group_id),
+                issued_at: Some(env::block_timestamp_ms()),
+                expires_at: expires_on.map(nanos_to_millis),
+                reference: None,
+                reference_hash: None,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[near(serializers = ["json"])]
+pub struct SbtToken {
+    pub token: u64,
+    pub owner: AccountId,
+    pub metadata: SbtTokenMetadata,
+}
+
+#[derive(Clone, Debug)]
+#[near(serializers = ["json"])]
+pub struct OwnedSbtToken {
+    pub token: u64,
+    pub metadata: SbtTokenMetadata,
+}
+
+pub fn class_of(group_id: u32) -> u64 {
+    group_id as u64 + 1
+}
+
+pub fn nanos_to_millis(timestamp: u64) -> u64 {
+    timestamp / 1_000_000
+}
+
+/// Logs a NEP-393 event, `data` holds the event specific fields.
+pub fn emit_sbt_event(event: &str, data: serde_json::Value) {
+    let event = serde_json::json!({
+        "standard": SBT_STANDARD,
+        "version": SBT_STANDARD_VERSION,
+        "event": event,
+        "data": data,
+    });
+    env::log_str(format!("EVENT_JSON:{}", event).as_str());
+}
@@ -0,0 +1,9 @@
+use near_sdk::near;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[near(serializers = ["json"])]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
@@ -0,0 +1,48 @@
+use near_sdk::{env, json_types::U128, near, AccountId};
+
+#[derive(Clone, Debug, PartialEq)]
+#[near(serializers = ["json", "borsh"])]
+pub enum TreasuryTransferKind {
+    JoinFee,
+    Withdrawal,
+    RenewalFee,
+}
+
+/// A movement of NEAR in or out of a group's treasury.
+#[derive(Clone, Debug)]
+#[near(serializers = ["json", "borsh"])]
+pub struct TreasuryTransfer {
+    pub kind: TreasuryTransferKind,
+    /// Payer of a fee or receiver of a withdrawal
+    pub account_id: AccountId,
+    /// Who triggered the transfer
+    pub initiated_by: AccountId,
+    pub amount: U128,
+    pub timestamp: u64,
+}
+
+impl TreasuryTransfer {
+    pub fn new(
+        kind: TreasuryTransferKind,
+        account_id: AccountId,
+        initiated_by: AccountId,
+        amount: u128,
+    ) -> Self {
+        Self {
+            kind,
+            account_id,
+            initiated_by,
+            amount: U128(amount),
+            timestamp: env::block_timestamp(),
+        }
+    }
+}
+
+/// NEAR collected from join and renewal fees, held by the contract on behalf of a group.
+#[derive(Clone, Debug, Default)]
+#[near(serializers = ["borsh"])]
+pub struct Treasury {
+    pub balance: u128,
+    /// Every transfer, oldest first
+    pub transfers: Vec<TreasuryTransfer>,
+}
@@ -0,0 +1,20 @@
+use near_sdk::{env, json_types::U128, near, AccountId};
+
+#[derive(Clone, Debug)]
+#[near(serializers = ["json", "borsh"])]
+pub struct WaitlistEntry {
+    pub account_id: AccountId,
+    pub waitlisted_on: u64,
+    /// Join fee held until the account is admitted, refunded otherwise
+    pub deposit: U128,
+}
+
+impl WaitlistEntry {
+    pub fn new(account_id: AccountId, deposit: u128) -> Self {
+        Self {
+            account_id,
+            waitlisted_on: env::block_timestamp(),
+            deposit: U128(deposit),
+        }
+    }
+}
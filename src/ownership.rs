@@ -0,0 +1,154 @@
+use crate::{
+    error::GroupError,
+    models::{application_role::ApplicationRole, ownership_transfer::OwnershipTransfer},
+    Contract, ContractExt,
+};
+
+use near_sdk::{env, near, AccountId};
+
+#[near]
+impl Contract {
+    #[handle_result]
+    pub fn propose_ownership_transfer(
+        &mut self,
+        group_id: u32,
+        new_owner: AccountId,
+        demote_to: Option<ApplicationRole>,
+    ) -> Result<(), GroupError> {
+        let account_id = env::predecessor_account_id();
+        let group = self
+            .groups
+            .get(&group_id)
+            .ok_or(GroupError::GroupNotFound)?;
+
+        if group.is_deleted {
+            return Err(GroupError::GroupDeleted);
+        }
+        if group.owner != account_id {
+            return Err(GroupError::Unauthorized);
+        }
+        if new_owner == account_id || !group.is_member(new_owner.clone()) {
+            return Err(GroupError::NotMember);
+        }
+        let demote_to = demote_to.unwrap_or(ApplicationRole::Admin);
+        if demote_to == ApplicationRole::Owner {
+            return Err(GroupError::InvalidRole);
+        }
+        if self.ownership_transfers.contains_key(&group_id) {
+            return Err(GroupError::TransferAlreadyPending);
+        }
+
+        self.ownership_transfers.insert(
+            group_id,
+            OwnershipTransfer {
+                group_id,
+                from: account_id.clone(),
+                to: new_owner.clone(),
+                demote_to,
+                created_on: env::block_timestamp(),
+            },
+        );
+        env::log_str(
+            format!(
+                "Ownership transfer of group {} proposed from {} to {}",
+                group_id, account_id, new_owner
+            )
+            .as_str(),
+        );
+        Ok(())
+    }
+
+    #[handle_result]
+    pub fn accept_ownership_transfer(&mut self, group_id: u32) -> Result<(), GroupError> {
+        let account_id = env::predecessor_account_id();
+        let transfer = self.get_transfer_for(group_id, &account_id)?;
+        self.ownership_transfers.remove(&group_id);
+
+        let group = self
+            .groups
+            .get_mut(&group_id)
+            .ok_or(GroupError::GroupNotFound)?;
+
+        // The proposal is stale once the proposer lost ownership or the new owner left
+        if group.owner != transfer.from || !group.is_member(account_id.clone()) {
+            return Err(GroupError::NoPendingTransfer);
+        }
+        group.set_owner(account_id.clone(), transfer.demote_to);
+        // Owners don't expire
+        self.renew_membership_token(group_id, &account_id);
+        self.index_group_listing(group_id);
+
+        env::log_str(
+            format!(
+                "Ownership of group {} transferred from {} to {}",
+                group_id, transfer.from, account_id
+            )
+            .as_str(),
+        );
+        Ok(())
+    }
+
+    #[handle_result]
+    pub fn reject_ownership_transfer(&mut self, group_id: u32) -> Result<(), GroupError> {
+        let account_id = env::predecessor_account_id();
+        self.get_transfer_for(group_id, &account_id)?;
+        self.ownership_transfers.remove(&group_id);
+        env::log_str(
+            format!(
+                "Ownership transfer of group {} rejected by {}",
+                group_id, account_id
+            )
+            .as_str(),
+        );
+        Ok(())
+    }
+
+    #[handle_result]
+    pub fn cancel_ownership_transfer(&mut self, group_id: u32) -> Result<(), GroupError> {
+        let account_id = env::predecessor_account_id();
+        let transfer = self
+            .ownership_transfers
+            .get(&group_id)
+            .ok_or(GroupError::NoPendingTransfer)?;
+
+        if transfer.from != account_id {
+            return Err(GroupError::Unauthorized);
+        }
+        self.ownership_transfers.remove(&group_id);
+        env::log_str(
+            format!(
+                "Ownership transfer of group {} cancelled by {}",
+                group_id, account_id
+            )
+            .as_str(),
+        );
+        Ok(())
+    }
+
+    pub fn get_ownership_transfer(&self, group_id: u32) -> Option<OwnershipTransfer> {
+        self.ownership_transfers.get(&group_id).cloned()
+    }
+
+    /// Pending transfers proposed by or to the given account.
+    pub fn get_pending_ownership_transfers(&self, account_id: AccountId) -> Vec<OwnershipTransfer> {
+        self.ownership_transfers
+            .values()
+            .filter(|transfer| transfer.from == account_id || transfer.to == account_id)
+            .cloned()
+            .collect()
+    }
+}
+
+impl Contract {
+    fn get_transfer_for(
+        &self,
+        group_id: u32,
+        account_id: &AccountId,
+    ) -> Result<OwnershipTransfer, GroupError> {
+        match self.ownership_transfers.get(&group_id) {
+            Some(transfer) if transfer.to == *account_id => Ok(transfer.clone()),
+            Some(_) => Err(GroupError::Unauthorized),
+            None => Err(GroupError::NoPendingTransfer),
+        }
+    }
+}
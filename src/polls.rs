@@ -0,0 +1,105 @@
+use crate::{
+    error::GroupError,
+    models::{
+        application_role::ApplicationRole,
+        permissions::Permission,
+        polls::{Poll, PollResponse, PostPoll},
+    },
+    Contract, ContractExt,
+};
+
+use near_sdk::{env, near, AccountId};
+
+#[near]
+impl Contract {
+    /// Starts a non-binding poll in a group, returns its id.
+    #[handle_result]
+    pub fn create_poll(&mut self, group_id: u32, post_poll: PostPoll) -> Result<u64, GroupError> {
+        let account_id = env::predecessor_account_id();
+        self.get_group_for_update(group_id, &account_id, Permission::ManagePolls)?;
+        if !post_poll.is_valid() {
+            return Err(GroupError::InvalidPoll);
+        }
+
+        self.poll_counter += 1;
+        let poll_id = self.poll_counter;
+        self.group_polls.entry(group_id).or_default().push(poll_id);
+        self.polls.insert(
+            poll_id,
+            Poll::new(poll_id, group_id, account_id.clone(), post_poll),
+        );
+        env::log_str(
+            format!(
+                "Poll {} for group {} created by {}",
+                poll_id, group_id, account_id
+            )
+            .as_str(),
+        );
+        Ok(poll_id)
+    }
+
+    /// Votes for one option, or several when the poll allows it, as an active member of its
+    /// group that isn't banned.
+    #[handle_result]
+    pub fn vote_in_poll(&mut self, poll_id: u64, options: Vec<u32>) -> Result<(), GroupError> {
+        let account_id = env::predecessor_account_id();
+        let poll = self.polls.get(&poll_id).ok_or(GroupError::PollNotFound)?;
+
+        if !poll.is_open() {
+            return Err(GroupError::PollClosed);
+        }
+        if poll.votes.contains_key(&account_id) {
+            return Err(GroupError::AlreadyVoted);
+        }
+        if !poll.is_valid_vote(&options) {
+            return Err(GroupError::InvalidPollVote);
+        }
+        let group = self
+            .groups
+            .get(&poll.group_id)
+            .ok_or(GroupError::GroupNotFound)?;
+
+        if group.is_deleted {
+            return Err(GroupError::GroupDeleted);
+        }
+        match group.members.role_of(&account_id) {
+            None => return Err(GroupError::NotMember),
+            Some(ApplicationRole::Banned) => return Err(GroupError::Banned),
+            Some(_) => {}
+        }
+
+        if let Some(poll) = self.polls.get_mut(&poll_id) {
+            poll.cast_vote(account_id.clone(), options);
+        }
+        env::log_str(format!("{} voted in poll {}", account_id, poll_id).as_str());
+        Ok(())
+    }
+
+    pub fn get_poll(&self, poll_id: u64) -> Option<PollResponse> {
+        self.polls
+            .get(&poll_id)
+            .map(|poll| PollResponse::new(poll_id, poll))
+    }
+
+    /// Polls of a group, oldest first.
+    pub fn get_group_polls(&self, group_id: u32, index: u32, limit: u32) -> Vec<PollResponse> {
+        self.group_polls
+            .get(&group_id)
+            .into_iter()
+            .flatten()
+            .skip(index as _)
+            .take(limit as _)
+            .filter_map(|poll_id| {
+                self.polls
+                    .get(poll_id)
+                    .map(|poll| PollResponse::new(*poll_id, poll))
+            })
+            .collect()
+    }
+
+    pub fn has_voted_in_poll(&self, poll_id: u64, account_id: AccountId) -> bool {
+        self.polls
+            .get(&poll_id)
+            .is_some_and(|poll| poll.votes.contains_key(&account_id))
+    }
+}
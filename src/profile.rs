@@ -1,4 +1,5 @@
-use crate::models::profile::{PostProfile, ProfileResponse, UpdateProfile};
+use crate::error::GroupError;
+use crate::models::profile::{is_matrix_user_id, PostProfile, ProfileResponse, UpdateProfile};
 use crate::models::rewards::Rewards;
 use crate::{Contract, ContractExt};
 use near_sdk::{env, near, AccountId};
@@ -10,6 +11,9 @@ use near_sdk::{env, near, AccountId};
 impl Contract {
     pub fn add_profile(&mut self, post_profile: PostProfile) {
         let account_id = env::signer_account_id();
+        // A profile added again starts without a Matrix user id
+        self.unlink_matrix_user_id(&account_id);
+        self.matrix_user_id_claims.remove(&account_id);
         self.profiles.insert(account_id, post_profile.into());
         env::log_str("Profile added");
     }
@@ -39,6 +43,99 @@ impl Contract {
         Some(())
     }
 
+    /// Claims a Matrix user id for the caller's profile, it is linked once the Matrix
+    /// verifier confirms the caller controls it. `None` unlinks the current one.
+    #[handle_result]
+    pub fn set_matrix_user_id(&mut self, matrix_user_id: Option<String>) -> Result<(), GroupError> {
+        let account_id = env::signer_account_id();
+        if matrix_user_id
+            .as_ref()
+            .is_some_and(|matrix_user_id| !is_matrix_user_id(matrix_user_id))
+        {
+            return Err(GroupError::InvalidMatrixUserId);
+        }
+        if !self.profiles.contains_key(&account_id) {
+            return Err(GroupError::ProfileNotFound);
+        }
+
+        match matrix_user_id {
+            Some(matrix_user_id) => {
+                if self
+                    .matrix_user_ids
+                    .get(&matrix_user_id)
+                    .is_some_and(|owner| *owner != account_id)
+                {
+                    return Err(GroupError::MatrixUserIdTaken);
+                }
+                self.matrix_user_id_claims
+                    .insert(account_id, matrix_user_id);
+                env::log_str("Matrix user id claimed");
+            }
+            None => {
+                self.matrix_user_id_claims.remove(&account_id);
+                self.unlink_matrix_user_id(&account_id);
+                env::log_str("Matrix user id unlinked");
+            }
+        }
+        Ok(())
+    }
+
+    /// Links a claimed Matrix user id once its owner proved control of it on Matrix,
+    /// replacing the id the account had linked before.
+    #[handle_result]
+    pub fn verify_matrix_user_id(
+        &mut self,
+        account_id: AccountId,
+        matrix_user_id: String,
+    ) -> Result<(), GroupError> {
+        if self.matrix_verifier.as_ref() != Some(&env::predecessor_account_id()) {
+            return Err(GroupError::Unauthorized);
+        }
+        if self.matrix_user_id_claims.get(&account_id) != Some(&matrix_user_id) {
+            return Err(GroupError::MatrixUserIdClaimNotFound);
+        }
+        if self
+            .matrix_user_ids
+            .get(&matrix_user_id)
+            .is_some_and(|owner| *owner != account_id)
+        {
+            return Err(GroupError::MatrixUserIdTaken);
+        }
+
+        self.matrix_user_id_claims.remove(&account_id);
+        self.unlink_matrix_user_id(&account_id);
+        let profile = self
+            .profiles
+            .get_mut(&account_id)
+            .ok_or(GroupError::ProfileNotFound)?;
+        profile.matrix_user_id = Some(matrix_user_id.clone());
+        profile.updated_on = env::block_timestamp();
+        self.matrix_user_ids
+            .insert(matrix_user_id.clone(), account_id.clone());
+        env::log_str(
+            format!("Matrix user id {} linked to {}", matrix_user_id, account_id).as_str(),
+        );
+        Ok(())
+    }
+
+    #[private]
+    pub fn set_matrix_verifier(&mut self, matrix_verifier: Option<AccountId>) {
+        self.matrix_verifier = matrix_verifier;
+    }
+
+    pub fn get_matrix_verifier(&self) -> Option<AccountId> {
+        self.matrix_verifier.clone()
+    }
+
+    /// Matrix user id an account claimed and that still waits for verification.
+    pub fn get_matrix_user_id_claim(&self, account_id: AccountId) -> Option<String> {
+        self.matrix_user_id_claims.get(&account_id).cloned()
+    }
+
+    pub fn get_account_by_matrix_user_id(&self, matrix_user_id: String) -> Option<AccountId> {
+        self.matrix_user_ids.get(&matrix_user_id).cloned()
+    }
+
     pub fn get_profile(&self, account_id: AccountId) -> Option<ProfileResponse> {
         let profile = self.profiles.get(&account_id)?;
         Some(ProfileResponse::new(account_id, profile.clone()))
@@ -54,3 +151,16 @@ impl Contract {
         profiles
     }
 }
+
+impl Contract {
+    /// Removes the verified Matrix user id of an account from its profile and the index.
+    fn unlink_matrix_user_id(&mut self, account_id: &AccountId) {
+        let Some(profile) = self.profiles.get_mut(account_id) else {
+            return;
+        };
+        if let Some(matrix_user_id) = profile.matrix_user_id.take() {
+            profile.updated_on = env::block_timestamp();
+            self.matrix_user_ids.remove(&matrix_user_id);
+        }
+    }
+}
@@ -0,0 +1,320 @@
+use crate::{
+    error::GroupError,
+    models::{
+        application_role::ApplicationRole,
+        proposals::{
+            CastVote, GovernanceSettings, PostProposal, Proposal, ProposalAction, ProposalResponse,
+            ProposalStatus, Vote,
+        },
+    },
+    Contract, ContractExt,
+};
+
+use near_sdk::{env, near, AccountId};
+
+#[near]
+impl Contract {
+    /// Quorum, threshold, voting period and voting weight of proposals created from now on.
+    #[handle_result]
+    pub fn set_group_governance(
+        &mut self,
+        group_id: u32,
+        settings: GovernanceSettings,
+    ) -> Result<(), GroupError> {
+        let account_id = env::predecessor_account_id();
+        if !settings.is_valid() {
+            return Err(GroupError::InvalidGovernanceSettings);
+        }
+        let group = self.get_owned_group_for_update(group_id, &account_id)?;
+        group.governance = settings;
+        group.updated_on = env::block_timestamp();
+        self.index_group_listing(group_id);
+        env::log_str(format!("Governance settings of group {} updated", group_id).as_str());
+        Ok(())
+    }
+
+    /// Opens a proposal for the members of a group to vote on, returns its id.
+    #[handle_result]
+    pub fn create_proposal(
+        &mut self,
+        group_id: u32,
+        post_proposal: PostProposal,
+    ) -> Result<u64, GroupError> {
+        let account_id = env::predecessor_account_id();
+        let group = self
+            .groups
+            .get(&group_id)
+            .ok_or(GroupError::GroupNotFound)?;
+
+        if group.is_deleted {
+            return Err(GroupError::GroupDeleted);
+        }
+        let role = group
+            .members
+            .role_of(&account_id)
+            .ok_or(GroupError::NotMember)?;
+        // Only those who could vote on it may propose
+        if group.governance.voting_weight.of(role) == 0 {
+            return Err(GroupError::NoVotingWeight);
+        }
+        match &post_proposal.action {
+            ProposalAction::Text => {}
+            ProposalAction::UpdateGroup { update } => {
+                if update.matrix_space_id.is_some() && group.owner != account_id {
+                    return Err(GroupError::Unauthorized);
+                }
+                if let Some(name) = &update.name {
+                    self.check_group_name_available(name, group_id)?;
+                }
+                if update
+                    .location
+                    .as_ref()
+                    .is_some_and(|location| !location.is_valid())
+                {
+                    return Err(GroupError::InvalidGeohash);
+                }
+            }
+            ProposalAction::ChangeRole { account_id, role } => {
+                check_role_change(group.owner == *account_id, role)?;
+                if !group.is_member(account_id.clone()) {
+                    return Err(GroupError::NotMember);
+                }
+            }
+            ProposalAction::TreasurySpend { amount, .. } => {
+                if amount.0 == 0 {
+                    return Err(GroupError::InsufficientTreasuryBalance);
+                }
+            }
+        }
+
+        let settings = group.governance.clone();
+        let total_weight = group
+            .members
+            .active()
+            .map(|(_, member)| settings.voting_weight.of(&member.role))
+            .sum();
+
+        self.proposal_counter += 1;
+        let proposal_id = self.proposal_counter;
+        self.group_proposals
+            .entry(group_id)
+            .or_default()
+            .push(proposal_id);
+        self.proposals.insert(
+            proposal_id,
+            Proposal::new(
+                proposal_id,
+                group_id,
+                account_id.clone(),
+                post_proposal,
+                settings,
+                total_weight,
+            ),
+        );
+        env::log_str(
+            format!(
+                "Proposal {} for group {} created by {}",
+                proposal_id, group_id, account_id
+            )
+            .as_str(),
+        );
+        Ok(proposal_id)
+    }
+
+    /// Votes weigh according to the settings the proposal was created with, only members
+    /// who joined before the proposal was created can vote.
+    #[handle_result]
+    pub fn vote_on_proposal(&mut self, proposal_id: u64, vote: Vote) -> Result<(), GroupError> {
+        let account_id = env::predecessor_account_id();
+        let proposal = self
+            .proposals
+            .get(&proposal_id)
+            .ok_or(GroupError::ProposalNotFound)?;
+
+        if !proposal.is_voting_open() {
+            return Err(GroupError::VotingClosed);
+        }
+        if proposal.votes.contains_key(&account_id) {
+            return Err(GroupError::AlreadyVoted);
+        }
+        let group = self
+            .groups
+            .get(&proposal.group_id)
+            .ok_or(GroupError::GroupNotFound)?;
+
+        if group.is_deleted {
+            return Err(GroupError::GroupDeleted);
+        }
+        let member = group
+            .members
+            .get_active(&account_id)
+            .ok_or(GroupError::NotMember)?;
+        // Members joining later weren't counted in the total weight
+        if member.joined_on > proposal.created_on {
+            return Err(GroupError::JoinedAfterProposal);
+        }
+        let weight = proposal.settings.voting_weight.of(&member.role);
+        if weight == 0 {
+            return Err(GroupError::NoVotingWeight);
+        }
+
+        if let Some(proposal) = self.proposals.get_mut(&proposal_id) {
+            proposal.cast_vote(account_id.clone(), CastVote { vote, weight });
+        }
+        env::log_str(
+            format!(
+                "{} voted {:?} on proposal {} with weight {}",
+                account_id, vote, proposal_id, weight
+            )
+            .as_str(),
+        );
+        Ok(())
+    }
+
+    /// Closes a proposal once its voting period is over and executes its action when
+    /// it passed. Anyone can finalize, an action that can no longer be carried out
+    /// marks the proposal as failed.
+    #[handle_result]
+    pub fn finalize_proposal(&mut self, proposal_id: u64) -> Result<ProposalStatus, GroupError> {
+        let proposal = self
+            .proposals
+            .get(&proposal_id)
+            .ok_or(GroupError::ProposalNotFound)?;
+
+        if proposal.status != ProposalStatus::Active {
+            return Err(GroupError::VotingClosed);
+        }
+        if env::block_timestamp() < proposal.voting_ends_on {
+            return Err(GroupError::VotingStillOpen);
+        }
+        let group_id = proposal.group_id;
+        if self
+            .groups
+            .get(&group_id)
+            .ok_or(GroupError::GroupNotFound)?
+            .is_deleted
+        {
+            return Err(GroupError::GroupDeleted);
+        }
+
+        let status = if !proposal.has_passed() {
+            ProposalStatus::Rejected
+        } else if let ProposalAction::Text = proposal.action {
+            ProposalStatus::Passed
+        } else {
+            let action = proposal.action.clone();
+            let proposer = proposal.proposer.clone();
+            match self.execute_proposal_action(group_id, proposer, action) {
+                Ok(()) => ProposalStatus::Executed,
+                Err(error) => {
+                    env::log_str(
+                        format!(
+                            "Proposal {} could not be executed: {}",
+                            proposal_id,
+                            error.as_ref()
+                        )
+                        .as_str(),
+                    );
+                    ProposalStatus::Failed
+                }
+            }
+        };
+
+        if let Some(proposal) = self.proposals.get_mut(&proposal_id) {
+            proposal.status = status.clone();
+        }
+        env::log_str(
+            format!(
+                "Proposal {} for group {} finalized as {:?}",
+                proposal_id, group_id, status
+            )
+            .as_str(),
+        );
+        Ok(status)
+    }
+
+    pub fn get_proposal(&self, proposal_id: u64) -> Option<ProposalResponse> {
+        self.proposals
+            .get(&proposal_id)
+            .map(|proposal| ProposalResponse::new(proposal_id, proposal))
+    }
+
+    /// Proposals of a group, oldest first, optionally only those with `status`.
+    pub fn get_group_proposals(
+        &self,
+        group_id: u32,
+        status: Option<ProposalStatus>,
+        index: u32,
+        limit: u32,
+    ) -> Vec<ProposalResponse> {
+        self.group_proposals
+            .get(&group_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|proposal_id| {
+                self.proposals
+                    .get(proposal_id)
+                    .map(|proposal| (proposal_id, proposal))
+            })
+            .filter(|(_, proposal)| {
+                status
+                    .as_ref()
+                    .is_none_or(|status| proposal.status == *status)
+            })
+            .skip(index as _)
+            .take(limit as _)
+            .map(|(proposal_id, proposal)| ProposalResponse::new(*proposal_id, proposal))
+            .collect()
+    }
+
+    pub fn get_proposal_vote(&self, proposal_id: u64, account_id: AccountId) -> Option<CastVote> {
+        self.proposals
+            .get(&proposal_id)?
+            .votes
+            .get(&account_id)
+            .cloned()
+    }
+}
+
+impl Contract {
+    fn execute_proposal_action(
+        &mut self,
+        group_id: u32,
+        proposer: AccountId,
+        action: ProposalAction,
+    ) -> Result<(), GroupError> {
+        match action {
+            ProposalAction::Text => Ok(()),
+            ProposalAction::UpdateGroup { update } => self.apply_group_update(group_id, *update),
+            ProposalAction::ChangeRole { account_id, role } => {
+                let group = self
+                    .groups
+                    .get_mut(&group_id)
+                    .ok_or(GroupError::GroupNotFound)?;
+
+                check_role_change(group.owner == account_id, &role)?;
+                if !group.is_member(account_id.clone()) {
+                    return Err(GroupError::NotMember);
+                }
+                group.set_member_role(account_id, role);
+                group.updated_on = env::block_timestamp();
+                self.index_group_listing(group_id);
+                Ok(())
+            }
+            ProposalAction::TreasurySpend {
+                receiver_id,
+                amount,
+            } => self
+                .spend_from_treasury(group_id, receiver_id, proposer, amount)
+                .map(|_| ()),
+        }
+    }
+}
+
+/// Ownership only changes hands through a transfer, proposals can't touch it either.
+fn check_role_change(is_owner: bool, role: &ApplicationRole) -> Result<(), GroupError> {
+    if is_owner || *role == ApplicationRole::Owner {
+        return Err(GroupError::InvalidRole);
+    }
+    Ok(())
+}
@@ -0,0 +1,249 @@
+use crate::{
+    models::sbt::{
+        class_of, emit_sbt_event, nanos_to_millis, MembershipToken, OwnedSbtToken,
+        SbtContractMetadata, SbtToken, SBT_METADATA_SPEC,
+    },
+    Contract, ContractExt,
+};
+
+use near_sdk::{env, near, serde_json::json, AccountId};
+
+/// NEP-393 views, the contract is both the issuer and the registry of its
+/// membership tokens, so other issuers never have any tokens here. Tokens are
+/// only minted, renewed and revoked by the contract itself, the registry's
+/// transfer, recovery and soul transfer calls aren't supported.
+#[near]
+impl Contract {
+    pub fn sbt_metadata(&self) -> SbtContractMetadata {
+        SbtContractMetadata {
+            spec: SBT_METADATA_SPEC.to_string(),
+            name: "Catalyze group membership".to_string(),
+            symbol: "CATMEMBER".to_string(),
+            icon: None,
+            base_uri: None,
+            reference: None,
+            reference_hash: None,
+        }
+    }
+
+    pub fn sbt(&self, issuer: AccountId, token: u64) -> Option<SbtToken> {
+        if issuer != env::current_account_id() {
+            return None;
+        }
+        self.sbt_token(token)
+    }
+
+    /// Tokens ordered by id, starting at `from_token`. Tokens of expired
+    /// memberships are left out unless `with_expired` is set.
+    pub fn sbt_tokens(
+        &self,
+        issuer: AccountId,
+        from_token: Option<u64>,
+        limit: Option<u32>,
+        with_expired: Option<bool>,
+    ) -> Vec<SbtToken> {
+        if issuer != env::current_account_id() {
+            return vec![];
+        }
+        let with_expired = with_expired.unwrap_or(false);
+        let now = env::block_timestamp_ms();
+        // Ids are handed out in order from 1, revoked ones leave gaps
+        (from_token.unwrap_or(1).max(1)..=self.membership_token_counter)
+            .filter_map(|token| self.sbt_token(token))
+            .filter(|token| {
+                with_expired
+                    || token
+                        .metadata
+                        .expires_at
+                        .is_none_or(|expires_at| now < expires_at)
+            })
+            .take(limit.unwrap_or(u32::MAX) as usize)
+            .collect()
+    }
+
+    /// Revoked tokens are burned, so any token that was issued and is gone was revoked.
+    pub fn sbt_revoked(&self, issuer: AccountId, token: u64) -> bool {
+        issuer == env::current_account_id()
+            && (1..=self.membership_token_counter).contains(&token)
+            && !self.membership_tokens.contains_key(&token)
+    }
+
+    pub fn sbt_supply(&self, issuer: AccountId) -> u64 {
+        if issuer != env::current_account_id() {
+            return 0;
+        }
+        self.membership_tokens.len() as u64
+    }
+
+    pub fn sbt_supply_by_class(&self, issuer: AccountId, class: u64) -> u64 {
+        if issuer != env::current_account_id() {
+            return 0;
+        }
+        self.membership_tokens
+            .values()
+            .filter(|token| token.metadata.class == class)
+            .count() as u64
+    }
+
+    pub fn sbt_supply_by_owner(
+        &self,
+        account: AccountId,
+        issuer: AccountId,
+        class: Option<u64>,
+    ) -> u64 {
+        if issuer != env::current_account_id() {
+            return 0;
+        }
+        self.owned_membership_tokens(&account)
+            .iter()
+            .filter(|token| class.is_none_or(|class| token.metadata.class == class))
+            .count() as u64
+    }
+
+    /// Membership tokens of an account ordered by class, that is by group id.
+    /// Tokens of expired memberships are left out unless `with_expired` is set.
+    pub fn sbt_tokens_by_owner(
+        &self,
+        account: AccountId,
+        issuer: Option<AccountId>,
+        from_class: Option<u64>,
+        limit: Option<u32>,
+        with_expired: Option<bool>,
+    ) -> Vec<(AccountId, Vec<OwnedSbtToken>)> {
+        let issuer_id = env::current_account_id();
+        if issuer.is_some_and(|issuer| issuer != issuer_id) {
+            return vec![];
+        }
+        let with_expired = with_expired.unwrap_or(false);
+        let now = env::block_timestamp_ms();
+        let mut tokens = self.owned_membership_tokens(&account);
+        tokens.retain(|token| token.metadata.class >= from_class.unwrap_or(0));
+        tokens.retain(|token| {
+            with_expired
+                || token
+                    .metadata
+                    .expires_at
+                    .is_none_or(|expires_at| now < expires_at)
+        });
+        tokens.sort_by_key(|token| token.metadata.class);
+        tokens.truncate(limit.unwrap_or(u32::MAX) as usize);
+
+        if tokens.is_empty() {
+            return vec![];
+        }
+        vec![(issuer_id, tokens)]
+    }
+}
+
+impl Contract {
+    /// Mints a token expiring with the account's membership of the group.
+    pub(crate) fn mint_membership_token(&mut self, group_id: u32, account_id: &AccountId) {
+        self.membership_token_counter += 1;
+        let token = self.membership_token_counter;
+        let expires_on = self.get_membership_expiry(group_id, account_id.clone());
+        self.membership_tokens.insert(
+            token,
+            MembershipToken::new(account_id.clone(), group_id, expires_on),
+        );
+        self.membership_tokens_by_owner
+            .entry(account_id.clone())
+            .or_default()
+            .push(token);
+
+        emit_sbt_event(
+            "mint",
+            json!({
+                "issuer": env::current_account_id(),
+                "tokens": [[account_id, [token]]],
+            }),
+        );
+    }
+
+    /// Moves the expiry of the account's token for the group to its membership expiry,
+    /// if it holds one and the expiry changed.
+    pub(crate) fn renew_membership_token(&mut self, group_id: u32, account_id: &AccountId) {
+        let Some(token) = self.membership_token_of(group_id, account_id) else {
+            return;
+        };
+        let expires_at = self
+            .get_membership_expiry(group_id, account_id.clone())
+            .map(nanos_to_millis);
+        let Some(membership_token) = self.membership_tokens.get_mut(&token) else {
+            return;
+        };
+        if membership_token.metadata.expires_at == expires_at {
+            return;
+        }
+        membership_token.metadata.expires_at = expires_at;
+
+        emit_sbt_event(
+            "renew",
+            json!({
+                "issuer": env::current_account_id(),
+                "tokens": [token],
+            }),
+        );
+    }
+
+    /// Burns the account's token for the group, if it holds one.
+    pub(crate) fn revoke_membership_token(&mut self, group_id: u32, account_id: &AccountId) {
+        let Some(token) = self.membership_token_of(group_id, account_id) else {
+            return;
+        };
+        let Some(tokens) = self.membership_tokens_by_owner.get_mut(account_id) else {
+            return;
+        };
+        tokens.retain(|owned| *owned != token);
+        if tokens.is_empty() {
+            self.membership_tokens_by_owner.remove(account_id);
+        }
+        self.membership_tokens.remove(&token);
+
+        emit_sbt_event(
+            "revoke",
+            json!({
+                "issuer": env::current_account_id(),
+                "tokens": [token],
+            }),
+        );
+    }
+
+    fn sbt_token(&self, token: u64) -> Option<SbtToken> {
+        self.membership_tokens
+            .get(&token)
+            .map(|membership_token| SbtToken {
+                token,
+                owner: membership_token.owner.clone(),
+                metadata: membership_token.metadata.clone(),
+            })
+    }
+
+    fn membership_token_of(&self, group_id: u32, account_id: &AccountId) -> Option<u64> {
+        let class = class_of(group_id);
+        self.membership_tokens_by_owner
+            .get(account_id)?
+            .iter()
+            .find(|token| {
+                self.membership_tokens
+                    .get(token)
+                    .is_some_and(|membership_token| membership_token.metadata.class == class)
+            })
+            .copied()
+    }
+
+    fn owned_membership_tokens(&self, account_id: &AccountId) -> Vec<OwnedSbtToken> {
+        self.membership_tokens_by_owner
+            .get(account_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|token| {
+                self.membership_tokens
+                    .get(token)
+                    .map(|membership_token| OwnedSbtToken {
+                        token: *token,
+                        metadata: membership_token.metadata.clone(),
+                    })
+            })
+            .collect()
+    }
+}
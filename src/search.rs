@@ -0,0 +1,137 @@
+use crate::{
+    models::groups::{GroupResponse, GroupVisibility},
+    Contract, ContractExt,
+};
+
+use near_sdk::{near, store::IterableMap};
+use std::collections::BTreeSet;
+
+/// Longest name prefix kept in the index, longer search words are checked against the name.
+const MAX_PREFIX_LENGTH: usize = 16;
+/// Distinct description words indexed per group, bounds the storage a single group can take.
+const MAX_DESCRIPTION_KEYWORDS: usize = 64;
+
+#[near]
+impl Contract {
+    /// Public groups matching every word of the query, either as the start of a
+    /// word in their name or as a word of their description. Ordered by id.
+    pub fn search_groups(&self, query: String, index: u32, limit: u32) -> Vec<GroupResponse> {
+        let mut matches: Option<BTreeSet<u32>> = None;
+        for word in tokenize(&query) {
+            let found = self.groups_matching(&word);
+            matches = Some(match matches {
+                Some(matches) => matches.intersection(&found).copied().collect(),
+                None => found,
+            });
+        }
+
+        matches
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|id| self.groups.get(&id).map(|group| (id, group)))
+            .filter(|(_, group)| !group.is_deleted)
+            .filter(|(_, group)| group.visibility == GroupVisibility::Public)
+            .skip(index as _)
+            .take(limit as _)
+            .map(|(id, group)| GroupResponse::new(id, group))
+            .collect()
+    }
+}
+
+impl Contract {
+    pub(crate) fn index_group_text(&mut self, group_id: u32, name: &str, description: &str) {
+        for prefix in name_prefixes(name) {
+            add_to_index(&mut self.group_name_prefixes, prefix, group_id);
+        }
+        for keyword in description_keywords(description) {
+            add_to_index(&mut self.group_keywords, keyword, group_id);
+        }
+    }
+
+    pub(crate) fn unindex_group_text(&mut self, group_id: u32, name: &str, description: &str) {
+        for prefix in name_prefixes(name) {
+            remove_from_index(&mut self.group_name_prefixes, prefix, group_id);
+        }
+        for keyword in description_keywords(description) {
+            remove_from_index(&mut self.group_keywords, keyword, group_id);
+        }
+    }
+
+    fn groups_matching(&self, word: &str) -> BTreeSet<u32> {
+        let prefix: String = word.chars().take(MAX_PREFIX_LENGTH).collect();
+        let by_name = self
+            .group_name_prefixes
+            .get(&prefix)
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(|id| {
+                prefix.len() == word.len()
+                    || self.groups.get(id).is_some_and(|group| {
+                        tokenize(&group.name)
+                            .iter()
+                            .any(|name_word| name_word.starts_with(word))
+                    })
+            });
+        let by_description = self.group_keywords.get(word).into_iter().flatten().copied();
+
+        by_name.chain(by_description).collect()
+    }
+}
+
+/// Lowercase words of a text, split on everything but letters and digits.
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+fn name_prefixes(name: &str) -> BTreeSet<String> {
+    tokenize(name)
+        .iter()
+        .flat_map(|word| {
+            word.char_indices()
+                .take(MAX_PREFIX_LENGTH)
+                .map(|(start, c)| word[..start + c.len_utf8()].to_string())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn description_keywords(description: &str) -> BTreeSet<String> {
+    let mut keywords = BTreeSet::new();
+    for word in tokenize(description) {
+        if keywords.len() == MAX_DESCRIPTION_KEYWORDS {
+            break;
+        }
+        // Single characters match too much to be useful as keywords
+        if word.chars().count() > 1 {
+            keywords.insert(word);
+        }
+    }
+    keywords
+}
+
+pub(crate) fn add_to_index(index: &mut IterableMap<String, Vec<u32>>, key: String, group_id: u32) {
+    let group_ids = index.entry(key).or_default();
+    if let Err(position) = group_ids.binary_search(&group_id) {
+        group_ids.insert(position, group_id);
+    }
+}
+
+pub(crate) fn remove_from_index(
+    index: &mut IterableMap<String, Vec<u32>>,
+    key: String,
+    group_id: u32,
+) {
+    let Some(group_ids) = index.get_mut(&key) else {
+        return;
+    };
+    if let Ok(position) = group_ids.binary_search(&group_id) {
+        group_ids.remove(position);
+    }
+    if group_ids.is_empty() {
+        index.remove(&key);
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{
+    error::GroupError,
+    models::{application_role::ApplicationRole, groups::GroupResponse},
+    Contract, ContractExt,
+};
+
+use near_sdk::{near, AccountId};
+
+#[near]
+impl Contract {
+    pub fn get_group_children(
+        &self,
+        group_id: u32,
+        include_deleted: Option<bool>,
+    ) -> Vec<GroupResponse> {
+        self.group_children
+            .get(&group_id)
+            .map(|children| {
+                children
+                    .iter()
+                    .filter_map(|id| self.get_group(*id, include_deleted))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Path from the top level group down to and including the given group.
+    /// Deleted groups are left out unless `include_deleted` is set.
+    pub fn get_group_ancestry(
+        &self,
+        group_id: u32,
+        include_deleted: Option<bool>,
+    ) -> Vec<GroupResponse> {
+        let include_deleted = include_deleted.unwrap_or(false);
+        let mut path = vec![];
+        let mut next = Some(group_id);
+        while let Some(id) = next {
+            let Some(group) = self.groups.get(&id) else {
+                break;
+            };
+            if include_deleted || !group.is_deleted {
+                path.push(GroupResponse::new(id, group));
+            }
+            next = group.parent_id;
+        }
+        path.reverse();
+        path
+    }
+}
+
+impl Contract {
+    pub(crate) fn check_parent_membership(
+        &self,
+        group_id: u32,
+        account_id: &AccountId,
+    ) -> Result<(), GroupError> {
+        let Some(group) = self.groups.get(&group_id) else {
+            return Ok(());
+        };
+        match group.parent_id {
+            Some(parent_id) if group.requires_parent_membership => {
+                // Expired and banned members of the parent don't count
+                if self.groups.get(&parent_id).is_some_and(|parent| {
+                    parent.members.is_active_member(account_id)
+                        && parent.role_of(account_id) != ApplicationRole::Banned
+                }) {
+                    Ok(())
+                } else {
+                    Err(GroupError::ParentMembershipRequired)
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn link_subgroup(&mut self, parent_id: u32, group_id: u32) {
+        self.group_children
+            .entry(parent_id)
+            .or_default()
+            .push(group_id);
+    }
+
+    /// Detaches a purged group from its parent and turns its children into top level groups.
+    pub(crate) fn unlink_subgroup(&mut self, group_id: u32, parent_id: Option<u32>) {
+        if let Some(parent_id) = parent_id {
+            if let Some(siblings) = self.group_children.get_mut(&parent_id) {
+                siblings.retain(|id| *id != group_id);
+                if siblings.is_empty() {
+                    self.group_children.remove(&parent_id);
+                }
+            }
+        }
+        for child_id in self.group_children.remove(&group_id).unwrap_or_default() {
+            if let Some(child) = self.groups.get_mut(&child_id) {
+                child.parent_id = None;
+                child.requires_parent_membership = false;
+            }
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{
+    error::GroupError,
+    models::{members::MemberResponse, treasury::TreasuryTransferKind},
+    Contract, ContractExt,
+};
+
+use near_sdk::{env, near, AccountId};
+
+#[near]
+impl Contract {
+    /// Makes memberships of accounts joining from now on last `duration` nanoseconds,
+    /// `None` makes new memberships permanent. Existing memberships keep their expiry.
+    #[handle_result]
+    pub fn set_group_membership_duration(
+        &mut self,
+        group_id: u32,
+        duration: Option<u64>,
+    ) -> Result<(), GroupError> {
+        let account_id = env::predecessor_account_id();
+        let group = self.get_owned_group_for_update(group_id, &account_id)?;
+        group.membership_duration = duration.filter(|duration| *duration > 0);
+        group.updated_on = env::block_timestamp();
+        self.index_group_listing(group_id);
+        env::log_str(format!("Membership duration of group {} updated", group_id).as_str());
+        Ok(())
+    }
+
+    /// Extends the caller's membership by the group's membership duration, counted from
+    /// its expiry or from now once expired. Costs the group's join fee, the rest of the
+    /// deposit is refunded. Returns the new expiry.
+    #[payable]
+    #[handle_result]
+    pub fn renew_membership(&mut self, group_id: u32) -> Result<u64, GroupError> {
+        let account_id = env::predecessor_account_id();
+        let deposit = env::attached_deposit().as_yoctonear();
+        let group = self
+            .groups
+            .get_mut(&group_id)
+            .ok_or(GroupError::GroupNotFound)?;
+
+        if group.is_deleted {
+            return Err(GroupError::GroupDeleted);
+        }
+        let duration = group
+            .membership_duration
+            .ok_or(GroupError::MembershipDoesNotExpire)?;
+        let join_fee = group.join_fee.map_or(0, |join_fee| join_fee.0);
+        if deposit < join_fee {
+            return Err(GroupError::InsufficientDeposit);
+        }
+        let member = group
+            .members
+            .members
+            .get(&account_id)
+            .ok_or(GroupError::NotMember)?;
+        let expires_on = member
+            .expires_on
+            .ok_or(GroupError::MembershipDoesNotExpire)?;
+        // An expired membership gave up its seat, taking it back needs a free one
+        if member.is_expired() && group.is_full() {
+            return Err(GroupError::GroupFull);
+        }
+
+        let expires_on = expires_on
+            .max(env::block_timestamp())
+            .saturating_add(duration);
+        group.members.set_expires_on(&account_id, Some(expires_on));
+        self.renew_membership_token(group_id, &account_id);
+        self.index_group_listing(group_id);
+
+        self.credit_treasury(
+            group_id,
+            &account_id,
+            join_fee,
+            TreasuryTransferKind::RenewalFee,
+        );
+        self.refund_deposit(&account_id, deposit - join_fee);
+        env::log_str(
+            format!(
+                "Membership of {} in group {} renewed until {}",
+                account_id, group_id, expires_on
+            )
+            .as_str(),
+        );
+        Ok(expires_on)
+    }
+
+    /// Active members whose membership runs out within `within` nanoseconds, soonest first.
+    pub fn get_upcoming_expirations(
+        &self,
+        group_id: u32,
+        within: u64,
+        index: u32,
+        limit: u32,
+    ) -> Vec<MemberResponse> {
+        let Some(group) = self.groups.get(&group_id) else {
+            return vec![];
+        };
+        let until = env::block_timestamp().saturating_add(within);
+        group
+            .members
+            .by_expiry()
+            .skip_while(|(_, member)| member.is_expired())
+            .take_while(|(_, member)| {
+                member
+                    .expires_on
+                    .is_some_and(|expires_on| expires_on <= until)
+            })
+            .skip(index as _)
+            .take(limit as _)
+            .map(|(account_id, member)| MemberResponse::new(account_id.clone(), member))
+            .collect()
+    }
+
+    pub fn get_membership_expiry(&self, group_id: u32, account_id: AccountId) -> Option<u64> {
+        self.groups
+            .get(&group_id)?
+            .members
+            .members
+            .get(&account_id)?
+            .expires_on
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{
+    models::groups::{GroupResponse, GroupVisibility},
+    Contract, ContractExt,
+};
+
+use near_sdk::near;
+use std::collections::BTreeSet;
+
+#[near]
+impl Contract {
+    /// Public groups carrying the given tags, ordered by id. With `match_all`
+    /// a group needs every tag, otherwise any of them.
+    pub fn get_groups_by_tag(
+        &self,
+        tags: Vec<u32>,
+        match_all: Option<bool>,
+        index: u32,
+        limit: u32,
+        include_deleted: Option<bool>,
+    ) -> Vec<GroupResponse> {
+        let include_deleted = include_deleted.unwrap_or(false);
+        let group_ids = if match_all.unwrap_or(false) {
+            self.groups_with_all_tags(&tags)
+        } else {
+            self.groups_with_any_tag(&tags)
+        };
+
+        group_ids
+            .into_iter()
+            .filter_map(|id| self.groups.get(&id).map(|group| (id, group)))
+            .filter(|(_, group)| include_deleted || !group.is_deleted)
+            .filter(|(_, group)| group.visibility == GroupVisibility::Public)
+            .skip(index as _)
+            .take(limit as _)
+            .map(|(id, group)| GroupResponse::new(id, group))
+            .collect()
+    }
+
+    /// Number of groups indexed under a tag, deleted and private ones included.
+    pub fn get_tag_group_count(&self, tag: u32) -> u32 {
+        self.groups_by_tag
+            .get(&tag)
+            .map(|group_ids| group_ids.len() as u32)
+            .unwrap_or_default()
+    }
+}
+
+impl Contract {
+    pub(crate) fn index_group_tags(&mut self, group_id: u32, tags: &[u32]) {
+        for tag in tags {
+            let group_ids = self.groups_by_tag.entry(*tag).or_default();
+            if let Err(position) = group_ids.binary_search(&group_id) {
+                group_ids.insert(position, group_id);
+            }
+        }
+    }
+
+    pub(crate) fn unindex_group_tags(&mut self, group_id: u32, tags: &[u32]) {
+        for tag in tags {
+            let Some(group_ids) = self.groups_by_tag.get_mut(tag) else {
+                continue;
+            };
+            if let Ok(position) = group_ids.binary_search(&group_id) {
+                group_ids.remove(position);
+            }
+            if group_ids.is_empty() {
+                self.groups_by_tag.remove(tag);
+            }
+        }
+    }
+
+    fn groups_with_any_tag(&self, tags: &[u32]) -> BTreeSet<u32> {
+        tags.iter()
+            .filter_map(|tag| self.groups_by_tag.get(tag))
+            .flatten()
+            .copied()
+            .collect()
+    }
+
+    fn groups_with_all_tags(&self, tags: &[u32]) -> BTreeSet<u32> {
+        let mut lists: Vec<&Vec<u32>> = Vec::with_capacity(tags.len());
+        for tag in tags {
+            match self.groups_by_tag.get(tag) {
+                Some(group_ids) => lists.push(group_ids),
+                None => return BTreeSet::new(),
+            }
+        }
+        // Walk the shortest list and look the others up
+        lists.sort_by_key(|group_ids| group_ids.len());
+        let Some((shortest, rest)) = lists.split_first() else {
+            return BTreeSet::new();
+        };
+        shortest
+            .iter()
+            .filter(|id| {
+                rest.iter()
+                    .all(|group_ids| group_ids.binary_search(id).is_ok())
+            })
+            .copied()
+            .collect()
+    }
+}
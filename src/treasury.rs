@@ -0,0 +1,218 @@
+use crate::{
+    error::GroupError,
+    models::{
+        permissions::Permission,
+        treasury::{TreasuryTransfer, TreasuryTransferKind},
+    },
+    Contract, ContractExt,
+};
+
+use near_sdk::{env, json_types::U128, near, AccountId, Gas, NearToken, Promise, PromiseResult};
+
+const GAS_FOR_WITHDRAWAL_CALLBACK: Gas = Gas::from_tgas(10);
+
+#[near]
+impl Contract {
+    /// NEAR every account pays when joining, whether directly, with an invite or through
+    /// a join request. Members added by a manager don't pay. `None` makes joining free.
+    #[handle_result]
+    pub fn set_group_join_fee(
+        &mut self,
+        group_id: u32,
+        join_fee: Option<U128>,
+    ) -> Result<(), GroupError> {
+        let account_id = env::predecessor_account_id();
+        let group = self.get_owned_group_for_update(group_id, &account_id)?;
+        group.join_fee = join_fee.filter(|join_fee| join_fee.0 > 0);
+        group.updated_on = env::block_timestamp();
+        self.index_group_listing(group_id);
+        env::log_str(format!("Join fee of group {} updated", group_id).as_str());
+        Ok(())
+    }
+
+    /// Sends NEAR from a group's treasury, to the caller unless `receiver_id` is given.
+    /// The amount goes back into the treasury if the transfer fails.
+    #[handle_result]
+    pub fn withdraw_from_treasury(
+        &mut self,
+        group_id: u32,
+        amount: U128,
+        receiver_id: Option<AccountId>,
+    ) -> Result<Promise, GroupError> {
+        let account_id = env::predecessor_account_id();
+        self.get_group_for_update(group_id, &account_id, Permission::ManageTreasury)?;
+
+        let receiver_id = receiver_id.unwrap_or_else(|| account_id.clone());
+        self.spend_from_treasury(group_id, receiver_id, account_id, amount)
+    }
+
+    /// Records a finished withdrawal, or puts the amount back when the transfer failed.
+    #[private]
+    pub fn on_treasury_withdrawal(
+        &mut self,
+        group_id: u32,
+        receiver_id: AccountId,
+        initiated_by: AccountId,
+        amount: U128,
+    ) -> bool {
+        let treasury = self.group_treasuries.entry(group_id).or_default();
+        if !matches!(env::promise_result(0), PromiseResult::Successful(_)) {
+            treasury.balance += amount.0;
+            env::log_str(
+                format!(
+                    "Withdrawal of {} yoctoNEAR from the treasury of group {} to {} failed",
+                    amount.0, group_id, receiver_id
+                )
+                .as_str(),
+            );
+            return false;
+        }
+
+        treasury.transfers.push(TreasuryTransfer::new(
+            TreasuryTransferKind::Withdrawal,
+            receiver_id.clone(),
+            initiated_by.clone(),
+            amount.0,
+        ));
+        env::log_str(
+            format!(
+                "{} yoctoNEAR withdrawn from the treasury of group {} to {} by {}",
+                amount.0, group_id, receiver_id, initiated_by
+            )
+            .as_str(),
+        );
+        true
+    }
+
+    pub fn get_group_treasury_balance(&self, group_id: u32) -> U128 {
+        U128(
+            self.group_treasuries
+                .get(&group_id)
+                .map(|treasury| treasury.balance)
+                .unwrap_or_default(),
+        )
+    }
+
+    /// Transfers in and out of a group's treasury, oldest first.
+    pub fn get_group_treasury_transfers(
+        &self,
+        group_id: u32,
+        index: u32,
+        limit: u32,
+    ) -> Vec<TreasuryTransfer> {
+        self.group_treasuries
+            .get(&group_id)
+            .map(|treasury| {
+                treasury
+                    .transfers
+                    .iter()
+                    .skip(index as _)
+                    .take(limit as _)
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl Contract {
+    pub(crate) fn join_fee_of(&self, group_id: u32) -> u128 {
+        self.groups
+            .get(&group_id)
+            .and_then(|group| group.join_fee)
+            .map_or(0, |join_fee| join_fee.0)
+    }
+
+    /// Pays the join fee out of `deposit` into the treasury and refunds the rest,
+    /// callers make sure the deposit covers the fee.
+    pub(crate) fn collect_join_fee(
+        &mut self,
+        group_id: u32,
+        account_id: &AccountId,
+        deposit: u128,
+    ) {
+        let join_fee = self.join_fee_of(group_id).min(deposit);
+        self.credit_treasury(
+            group_id,
+            account_id,
+            join_fee,
+            TreasuryTransferKind::JoinFee,
+        );
+        self.refund_deposit(account_id, deposit - join_fee);
+    }
+
+    pub(crate) fn credit_treasury(
+        &mut self,
+        group_id: u32,
+        account_id: &AccountId,
+        amount: u128,
+        kind: TreasuryTransferKind,
+    ) {
+        if amount == 0 {
+            return;
+        }
+        env::log_str(
+            format!(
+                "{} yoctoNEAR paid by {} into the treasury of group {}",
+                amount, account_id, group_id
+            )
+            .as_str(),
+        );
+        let treasury = self.group_treasuries.entry(group_id).or_default();
+        treasury.balance += amount;
+        treasury.transfers.push(TreasuryTransfer::new(
+            kind,
+            account_id.clone(),
+            account_id.clone(),
+            amount,
+        ));
+    }
+
+    /// Takes `amount` out of the treasury and sends it, callers check who may spend.
+    pub(crate) fn spend_from_treasury(
+        &mut self,
+        group_id: u32,
+        receiver_id: AccountId,
+        initiated_by: AccountId,
+        amount: U128,
+    ) -> Result<Promise, GroupError> {
+        let treasury = self.group_treasuries.entry(group_id).or_default();
+        if amount.0 == 0 || amount.0 > treasury.balance {
+            return Err(GroupError::InsufficientTreasuryBalance);
+        }
+        treasury.balance -= amount.0;
+
+        Ok(Promise::new(receiver_id.clone())
+            .transfer(NearToken::from_yoctonear(amount.0))
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(GAS_FOR_WITHDRAWAL_CALLBACK)
+                    .on_treasury_withdrawal(group_id, receiver_id, initiated_by, amount),
+            ))
+    }
+
+    pub(crate) fn refund_deposit(&self, account_id: &AccountId, amount: u128) {
+        if amount == 0 {
+            return;
+        }
+        Promise::new(account_id.clone()).transfer(NearToken::from_yoctonear(amount));
+        env::log_str(format!("Refunded {} yoctoNEAR to {}", amount, account_id).as_str());
+    }
+
+    /// Pays what is left in the treasury of a purged group out to its last owner.
+    pub(crate) fn close_treasury(&mut self, group_id: u32, owner: &AccountId) {
+        let Some(treasury) = self.group_treasuries.remove(&group_id) else {
+            return;
+        };
+        if treasury.balance > 0 {
+            Promise::new(owner.clone()).transfer(NearToken::from_yoctonear(treasury.balance));
+            env::log_str(
+                format!(
+                    "{} yoctoNEAR left in the treasury of group {} sent to {}",
+                    treasury.balance, group_id, owner
+                )
+                .as_str(),
+            );
+        }
+    }
+}
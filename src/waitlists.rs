@@ -0,0 +1,170 @@
+use crate::{
+    error::GroupError,
+    models::{
+        application_role::ApplicationRole, treasury::TreasuryTransferKind, waitlist::WaitlistEntry,
+    },
+    Contract, ContractExt,
+};
+
+use near_sdk::{env, near, AccountId};
+
+#[near]
+impl Contract {
+    /// Leaves a group's waitlist, a held join fee is refunded.
+    #[handle_result]
+    pub fn leave_group_waitlist(&mut self, group_id: u32) -> Result<(), GroupError> {
+        let account_id = env::predecessor_account_id();
+        let entry = self
+            .take_waitlist_entry(group_id, &account_id)
+            .ok_or(GroupError::NotWaitlisted)?;
+        self.refund_deposit(&account_id, entry.deposit.0);
+        env::log_str(
+            format!(
+                "Account {} left the waitlist of group {}",
+                account_id, group_id
+            )
+            .as_str(),
+        );
+        Ok(())
+    }
+
+    /// Waitlisted accounts of a group, next in line first.
+    pub fn get_group_waitlist(&self, group_id: u32, index: u32, limit: u32) -> Vec<WaitlistEntry> {
+        self.group_waitlists
+            .get(&group_id)
+            .map(|waitlist| {
+                waitlist
+                    .iter()
+                    .skip(index as _)
+                    .take(limit as _)
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Position of an account on a group's waitlist, 1 is next in line.
+    pub fn get_waitlist_position(&self, group_id: u32, account_id: AccountId) -> Option<u32> {
+        self.group_waitlists
+            .get(&group_id)?
+            .iter()
+            .position(|entry| entry.account_id == account_id)
+            .map(|position| position as u32 + 1)
+    }
+}
+
+impl Contract {
+    pub(crate) fn join_waitlist(
+        &mut self,
+        group_id: u32,
+        account_id: &AccountId,
+        deposit: u128,
+    ) -> Result<(), GroupError> {
+        if self.is_banned(group_id, account_id) {
+            return Err(GroupError::Banned);
+        }
+        self.check_parent_membership(group_id, account_id)?;
+        if !self.profiles.contains_key(account_id) {
+            return Err(GroupError::ProfileNotFound);
+        }
+        let group = self
+            .groups
+            .get(&group_id)
+            .ok_or(GroupError::GroupNotFound)?;
+
+        if group.is_deleted {
+            return Err(GroupError::GroupDeleted);
+        }
+        if group.is_member(account_id.clone()) {
+            return Err(GroupError::UserAlreadyInGroup);
+        }
+
+        let waitlist = self.group_waitlists.entry(group_id).or_default();
+        if waitlist.iter().any(|entry| entry.account_id == *account_id) {
+            return Err(GroupError::AlreadyWaitlisted);
+        }
+        waitlist.push(WaitlistEntry::new(account_id.clone(), deposit));
+
+        env::log_str(
+            format!(
+                "Group {} is full, {} waitlisted at position {}",
+                group_id,
+                account_id,
+                waitlist.len()
+            )
+            .as_str(),
+        );
+        Ok(())
+    }
+
+    pub(crate) fn take_waitlist_entry(
+        &mut self,
+        group_id: u32,
+        account_id: &AccountId,
+    ) -> Option<WaitlistEntry> {
+        let waitlist = self.group_waitlists.get_mut(&group_id)?;
+        let position = waitlist
+            .iter()
+            .position(|entry| entry.account_id == *account_id)?;
+        let entry = waitlist.remove(position);
+
+        if waitlist.is_empty() {
+            self.group_waitlists.remove(&group_id);
+        }
+        Some(entry)
+    }
+
+    /// Admits waitlisted accounts in order while the group has free seats, their held
+    /// join fee goes to the treasury. Accounts that can no longer be admitted are
+    /// dropped from the waitlist and refunded.
+    pub(crate) fn fill_from_waitlist(&mut self, group_id: u32) {
+        loop {
+            let Some(group) = self.groups.get(&group_id) else {
+                return;
+            };
+            if group.is_deleted || group.is_full() {
+                return;
+            }
+            let Some(entry) = self
+                .group_waitlists
+                .get(&group_id)
+                .and_then(|waitlist| waitlist.first())
+                .cloned()
+            else {
+                return;
+            };
+            let account_id = entry.account_id;
+            self.take_waitlist_entry(group_id, &account_id);
+
+            match self.admit_member(group_id, &account_id, ApplicationRole::Member, None) {
+                Ok(()) => {
+                    env::log_str(
+                        format!(
+                            "Account {} promoted from the waitlist of group {}",
+                            account_id, group_id
+                        )
+                        .as_str(),
+                    );
+                    self.credit_treasury(
+                        group_id,
+                        &account_id,
+                        entry.deposit.0,
+                        TreasuryTransferKind::JoinFee,
+                    );
+                }
+                Err(error) => {
+                    env::log_str(
+                        format!(
+                            "Account {} dropped from the waitlist of group {}: {}",
+                            account_id,
+                            group_id,
+                            error.as_ref()
+                        )
+                        .as_str(),
+                    );
+                    self.refund_deposit(&account_id, entry.deposit.0);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,101 @@
+mod common;
+
+use cat_near_contract::models::bans::GroupBan;
+use common::{add_group, add_profile, init};
+use near_sdk::serde_json::json;
+
+#[tokio::test]
+async fn test_ban_blocks_rejoining() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let member = sandbox.dev_create_account().await?;
+    add_profile(&contract, &member, "member").await?;
+    let _ = member
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+
+    let outcome = owner
+        .call(contract.id(), "ban_group_member")
+        .args_json(json!({
+            "group_id": group_id,
+            "account_id": member.id(),
+            "reason": "Spam"
+        }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success(), "Owner should ban members");
+
+    let is_in_group: bool = contract
+        .view("is_user_in_group")
+        .args_json(json!({ "account_id": member.id(), "group_id": group_id }))
+        .await?
+        .json()?;
+    assert!(!is_in_group, "Banned member should be removed");
+
+    let outcome = member
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure(), "Banned account should not rejoin");
+
+    let bans: Vec<GroupBan> = contract
+        .view("get_group_bans")
+        .args_json(json!({ "group_id": group_id, "index": 0, "limit": 10 }))
+        .await?
+        .json()?;
+    assert_eq!(bans.len(), 1);
+    assert_eq!(bans[0].account_id, *member.id());
+    assert_eq!(bans[0].reason, "Spam");
+
+    let outcome = owner
+        .call(contract.id(), "unban_group_member")
+        .args_json(json!({ "group_id": group_id, "account_id": member.id() }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success(), "Owner should unban accounts");
+
+    let outcome = member
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success(), "Unbanned account should rejoin");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_members_cannot_ban() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let member = sandbox.dev_create_account().await?;
+    add_profile(&contract, &member, "member").await?;
+    let _ = member
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+
+    let outcome = member
+        .call(contract.id(), "ban_group_member")
+        .args_json(json!({ "group_id": group_id, "account_id": owner.id() }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure(), "Members should not ban");
+
+    let is_banned: bool = contract
+        .view("is_user_banned_from_group")
+        .args_json(json!({ "account_id": owner.id(), "group_id": group_id }))
+        .await?
+        .json()?;
+    assert!(!is_banned);
+
+    Ok(())
+}
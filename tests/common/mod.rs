@@ -0,0 +1,64 @@
+// Not every test file uses every fixture
+#![allow(dead_code)]
+
+use near_sdk::serde_json::json;
+use near_workspaces::{network::Sandbox, Account, Contract, Worker};
+
+pub async fn init() -> Result<(Worker<Sandbox>, Contract, Account), Box<dyn std::error::Error>> {
+    let sandbox = near_workspaces::sandbox().await?;
+    let contract_wasm = near_workspaces::compile_project("./").await?;
+    let contract = sandbox.dev_deploy(&contract_wasm).await?;
+
+    let user_account = sandbox.dev_create_account().await?;
+
+    let outcome = user_account
+        .call(contract.id(), "new")
+        .args_json(json!({}))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    Ok((sandbox, contract, user_account))
+}
+
+pub async fn add_profile(
+    contract: &Contract,
+    user: &Account,
+    username: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let _ = user
+        .call(contract.id(), "add_profile")
+        .args_json(json!({"post_profile": {
+            "username": username,
+            "display_name": username,
+            "first_name": "Test",
+            "last_name": "Test",
+            "extra": "Test"
+        }}))
+        .transact()
+        .await?;
+    Ok(())
+}
+
+pub async fn add_group(
+    contract: &Contract,
+    owner: &Account,
+) -> Result<u32, Box<dyn std::error::Error>> {
+    let group_id = owner
+        .call(contract.id(), "add_group")
+        .args_json(json!({
+            "post_group": {
+                "name": "Test Group",
+                "description": "A test group",
+                "website": "https://example.com",
+                "image": "ipfs://example",
+                "banner_image": "ipfs://example_banner",
+                "matrix_space_id": "space123",
+                "tags": vec![1, 2, 3]
+            }
+        }))
+        .transact()
+        .await?
+        .json::<u32>()?;
+    Ok(group_id)
+}
@@ -0,0 +1,5 @@
+pub mod application_role;
+pub mod groups;
+pub mod members;
+pub mod profile;
+pub mod rewards;
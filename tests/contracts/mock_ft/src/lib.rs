@@ -0,0 +1,26 @@
+// Only implements the part of NEP-141 the token gates read, balances are set directly.
+use near_sdk::{json_types::U128, near, store::LookupMap, AccountId};
+
+#[near(contract_state)]
+pub struct Contract {
+    balances: LookupMap<AccountId, u128>,
+}
+
+impl Default for Contract {
+    fn default() -> Self {
+        Self {
+            balances: LookupMap::new(b"b"),
+        }
+    }
+}
+
+#[near]
+impl Contract {
+    pub fn set_balance(&mut self, account_id: AccountId, balance: U128) {
+        self.balances.insert(account_id, balance.0);
+    }
+
+    pub fn ft_balance_of(&self, account_id: AccountId) -> U128 {
+        U128(self.balances.get(&account_id).copied().unwrap_or_default())
+    }
+}
@@ -0,0 +1,46 @@
+// Only implements the part of NEP-171 the token gates read, tokens are minted directly.
+use near_sdk::{json_types::U128, near, store::IterableMap, AccountId};
+
+#[near(serializers = ["json"])]
+pub struct Token {
+    pub token_id: String,
+    pub owner_id: AccountId,
+}
+
+#[near(contract_state)]
+pub struct Contract {
+    owners: IterableMap<String, AccountId>,
+}
+
+impl Default for Contract {
+    fn default() -> Self {
+        Self {
+            owners: IterableMap::new(b"o"),
+        }
+    }
+}
+
+#[near]
+impl Contract {
+    pub fn nft_mint(&mut self, token_id: String, owner_id: AccountId) {
+        self.owners.insert(token_id, owner_id);
+    }
+
+    pub fn nft_tokens_for_owner(
+        &self,
+        account_id: AccountId,
+        from_index: Option<U128>,
+        limit: Option<u64>,
+    ) -> Vec<Token> {
+        self.owners
+            .iter()
+            .filter(|(_, owner_id)| **owner_id == account_id)
+            .skip(from_index.map_or(0, |index| index.0 as usize))
+            .take(limit.map_or(usize::MAX, |limit| limit as usize))
+            .map(|(token_id, owner_id)| Token {
+                token_id: token_id.clone(),
+                owner_id: owner_id.clone(),
+            })
+            .collect()
+    }
+}
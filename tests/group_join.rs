@@ -1,3 +1,4 @@
+use cat_near_contract::models::groups::GroupResponse;
 use near_sdk::serde_json::json;
 use near_sdk::AccountId;
 use near_workspaces::{network::Sandbox, Account, Contract, Worker};
@@ -98,7 +99,7 @@ async fn test_join_group() -> Result<(), Box<dyn std::error::Error>> {
 
 #[tokio::test]
 async fn test_leave_group() -> Result<(), Box<dyn std::error::Error>> {
-    let (_, contract, user) = init().await?;
+    let (sandbox, contract, user) = init().await?;
 
     // First, create a profile for the user
     let _ = user
@@ -133,22 +134,40 @@ async fn test_leave_group() -> Result<(), Box<dyn std::error::Error>> {
         .json::<u32>()
         .unwrap();
 
+    let new_user = sandbox.dev_create_account().await?;
+    let _ = new_user
+        .call(contract.id(), "add_profile")
+        .args_json(json!({"post_profile": {
+            "username": "testname",
+            "display_name": "Test",
+            "first_name": "Test",
+            "last_name": "Test",
+            "extra": "Test"
+        }}))
+        .transact()
+        .await?;
+    let _ = new_user
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+
     // Leave the group
-    let result = user
+    let result = new_user
         .call(contract.id(), "leave_group")
         .args_json(json!({ "group_id": group_id }))
         .transact()
-        .await;
+        .await?;
 
     println!("result: {:#?}", result);
 
-    assert!(result.is_ok(), "Failed to leave group: {:?}", result.err());
+    assert!(result.is_success(), "Failed to leave group: {:?}", result);
 
     // Verify user is not in the group
     let is_in_group: bool = contract
         .view("is_user_in_group")
         .args_json(json!({
-            "account_id": user.id(),
+            "account_id": new_user.id(),
             "group_id": group_id
         }))
         .await?
@@ -158,6 +177,98 @@ async fn test_leave_group() -> Result<(), Box<dyn std::error::Error>> {
 
     assert!(!is_in_group, "User should not be in the group");
 
+    // The owner can't leave without a successor
+    let result = user
+        .call(contract.id(), "leave_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+
+    assert!(result.is_failure(), "Owner should not be able to leave");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_leave_group_with_succession() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, user) = init().await?;
+
+    let _ = user
+        .call(contract.id(), "add_profile")
+        .args_json(json!({"post_profile": {
+            "username": "jassification",
+            "display_name": "Jas",
+            "first_name": "Jaswinder",
+            "last_name": "Singh",
+            "extra": "extra"
+        }}))
+        .transact()
+        .await?;
+
+    let group_id: u32 = user
+        .call(contract.id(), "add_group")
+        .args_json(json!({
+            "post_group": {
+                "name": "Test Group",
+                "description": "A test group",
+                "website": "https://example.com",
+                "image": "ipfs://example",
+                "banner_image": "ipfs://example_banner",
+                "matrix_space_id": "space123",
+                "tags": vec![1, 2, 3]
+            }
+        }))
+        .transact()
+        .await?
+        .json()?;
+
+    let admin = sandbox.dev_create_account().await?;
+    let _ = admin
+        .call(contract.id(), "add_profile")
+        .args_json(json!({"post_profile": {
+            "username": "testname",
+            "display_name": "Test",
+            "first_name": "Test",
+            "last_name": "Test",
+            "extra": "Test"
+        }}))
+        .transact()
+        .await?;
+    let _ = user
+        .call(contract.id(), "add_group_member")
+        .args_json(json!({ "group_id": group_id, "account_id": admin.id(), "role": "Admin" }))
+        .transact()
+        .await?;
+
+    let _ = user
+        .call(contract.id(), "set_succession_policy")
+        .args_json(json!({ "group_id": group_id, "policy": "LongestStandingAdmin" }))
+        .transact()
+        .await?;
+
+    let result = user
+        .call(contract.id(), "leave_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+
+    assert!(result.is_success(), "Owner should leave with a successor");
+
+    let role: Option<String> = contract
+        .view("get_user_role_in_group")
+        .args_json(json!({
+            "account_id": admin.id(),
+            "group_id": group_id
+        }))
+        .await?
+        .json()?;
+
+    assert_eq!(
+        role,
+        Some("Owner".to_string()),
+        "Admin should inherit ownership"
+    );
+
     Ok(())
 }
 
@@ -364,3 +475,90 @@ async fn test_get_user_role_in_group() -> Result<(), Box<dyn std::error::Error>>
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_group_members_count() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, user) = init().await?;
+
+    let _ = user
+        .call(contract.id(), "add_profile")
+        .args_json(json!({"post_profile": {
+            "username": "jassification",
+            "display_name": "Jas",
+            "first_name": "Jaswinder",
+            "last_name": "Singh",
+            "extra": "Test"
+        }}))
+        .transact()
+        .await?;
+
+    let group_id: u32 = user
+        .call(contract.id(), "add_group")
+        .args_json(json!({
+            "post_group": {
+                "name": "Test Group",
+                "description": "A test group",
+                "website": "https://example.com",
+                "image": "ipfs://example",
+                "banner_image": "ipfs://example_banner",
+                "matrix_space_id": "space123",
+                "tags": vec![1, 2, 3]
+            }
+        }))
+        .transact()
+        .await?
+        .json()?;
+
+    let mut users = vec![];
+    for i in 0..3 {
+        let new_user = sandbox.dev_create_account().await?;
+        let _ = new_user
+            .call(contract.id(), "add_profile")
+            .args_json(json!({"post_profile": {
+                "username": format!("testuser{}", i),
+                "display_name": format!("Test User {}", i),
+                "first_name": "Test",
+                "last_name": "Test",
+                "extra": "extra"
+            }}))
+            .transact()
+            .await?;
+        let _ = new_user
+            .call(contract.id(), "join_group")
+            .args_json(json!({ "group_id": group_id }))
+            .transact()
+            .await?;
+        users.push(new_user);
+    }
+
+    let _ = users[0]
+        .call(contract.id(), "leave_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+
+    let group: Option<GroupResponse> = contract
+        .view("get_group")
+        .args_json(json!({ "id": group_id }))
+        .await?
+        .json()?;
+
+    assert_eq!(
+        group.unwrap().members_count,
+        3,
+        "Group should count 3 members"
+    );
+
+    let is_in_group: bool = contract
+        .view("is_user_in_group")
+        .args_json(json!({
+            "account_id": users[1].id(),
+            "group_id": group_id
+        }))
+        .await?
+        .json()?;
+
+    assert!(is_in_group, "Remaining member should still be in the group");
+
+    Ok(())
+}
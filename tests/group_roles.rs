@@ -0,0 +1,479 @@
+mod common;
+
+use cat_near_contract::models::{application_role::ApplicationRole, members::MemberResponse};
+use common::{add_group, add_profile, init};
+use near_sdk::borsh;
+use near_sdk::serde_json::json;
+
+#[tokio::test]
+async fn test_get_user_permissions_in_group() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let member = sandbox.dev_create_account().await?;
+    add_profile(&contract, &member, "member").await?;
+    let _ = member
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+
+    let owner_permissions: Vec<String> = contract
+        .view("get_user_permissions_in_group")
+        .args_json(json!({ "account_id": owner.id(), "group_id": group_id }))
+        .await?
+        .json()?;
+    assert_eq!(
+        owner_permissions.len(),
+        8,
+        "Owner should have every permission"
+    );
+
+    let member_permissions: Vec<String> = contract
+        .view("get_user_permissions_in_group")
+        .args_json(json!({ "account_id": member.id(), "group_id": group_id }))
+        .await?
+        .json()?;
+    assert!(
+        member_permissions.is_empty(),
+        "Member should have no permissions"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_group_role_permissions() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let member = sandbox.dev_create_account().await?;
+    add_profile(&contract, &member, "member").await?;
+    let _ = member
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+
+    // Members can't grant themselves permissions
+    let outcome = member
+        .call(contract.id(), "set_group_role_permissions")
+        .args_json(json!({
+            "group_id": group_id,
+            "role": "Member",
+            "permissions": ["EditMetadata"]
+        }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure(), "Member should not change permissions");
+
+    let outcome = owner
+        .call(contract.id(), "set_group_role_permissions")
+        .args_json(json!({
+            "group_id": group_id,
+            "role": "Member",
+            "permissions": ["EditMetadata"]
+        }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success(), "Owner should change permissions");
+
+    let member_permissions: Vec<String> = contract
+        .view("get_user_permissions_in_group")
+        .args_json(json!({ "account_id": member.id(), "group_id": group_id }))
+        .await?
+        .json()?;
+    assert_eq!(member_permissions, vec!["EditMetadata".to_string()]);
+
+    // The override now lets members edit the group
+    let outcome = member
+        .call(contract.id(), "edit_group")
+        .args_json(json!({
+            "id": group_id,
+            "update_group": { "description": "Edited by a member" }
+        }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success(), "Member should be able to edit");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admin_can_not_grant_owner_permissions() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let admin = sandbox.dev_create_account().await?;
+    add_profile(&contract, &admin, "admin").await?;
+    let _ = admin
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    let _ = owner
+        .call(contract.id(), "set_group_member_role")
+        .args_json(json!({
+            "group_id": group_id,
+            "account_id": admin.id(),
+            "role": "Admin"
+        }))
+        .transact()
+        .await?;
+
+    // Admins could otherwise hand a lower role what they don't have and promote a second account
+    let outcome = admin
+        .call(contract.id(), "set_group_role_permissions")
+        .args_json(json!({
+            "group_id": group_id,
+            "role": "Moderator",
+            "permissions": ["ModerateContent", "DeleteGroup"]
+        }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure(), "Admin should not grant DeleteGroup");
+
+    let outcome = admin
+        .call(contract.id(), "set_group_role_permissions")
+        .args_json(json!({
+            "group_id": group_id,
+            "role": "Member",
+            "permissions": ["ManageTreasury"]
+        }))
+        .transact()
+        .await?;
+    assert!(
+        outcome.is_failure(),
+        "Admin should not grant ManageTreasury"
+    );
+
+    let moderator_permissions: Vec<String> = contract
+        .view("get_group_role_permissions")
+        .args_json(json!({ "group_id": group_id }))
+        .await?
+        .json::<std::collections::HashMap<String, Vec<String>>>()?
+        .remove("Moderator")
+        .unwrap_or_default();
+    assert_eq!(moderator_permissions, vec!["ModerateContent".to_string()]);
+
+    // Permissions the admin holds can still be handed out
+    let outcome = admin
+        .call(contract.id(), "set_group_role_permissions")
+        .args_json(json!({
+            "group_id": group_id,
+            "role": "Member",
+            "permissions": ["ModerateContent"]
+        }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success(), "Admin should grant held permissions");
+
+    // The owner can grant either
+    let outcome = owner
+        .call(contract.id(), "set_group_role_permissions")
+        .args_json(json!({
+            "group_id": group_id,
+            "role": "Admin",
+            "permissions": ["ManageRoles", "ManageTreasury"]
+        }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success(), "Owner should grant ManageTreasury");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_only_owner_assigns_treasurer() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let admin = sandbox.dev_create_account().await?;
+    add_profile(&contract, &admin, "admin").await?;
+    let member = sandbox.dev_create_account().await?;
+    add_profile(&contract, &member, "member").await?;
+    for account in [&admin, &member] {
+        let _ = account
+            .call(contract.id(), "join_group")
+            .args_json(json!({ "group_id": group_id }))
+            .transact()
+            .await?;
+    }
+    let _ = owner
+        .call(contract.id(), "set_group_member_role")
+        .args_json(json!({
+            "group_id": group_id,
+            "account_id": admin.id(),
+            "role": "Admin"
+        }))
+        .transact()
+        .await?;
+
+    // Treasurer ranks below Admin but holds the owner only ManageTreasury
+    let outcome = admin
+        .call(contract.id(), "set_group_member_role")
+        .args_json(json!({
+            "group_id": group_id,
+            "account_id": member.id(),
+            "role": "Treasurer"
+        }))
+        .transact()
+        .await?;
+    assert!(
+        outcome.is_failure(),
+        "Admin should not promote to Treasurer"
+    );
+
+    let outsider = sandbox.dev_create_account().await?;
+    add_profile(&contract, &outsider, "outsider").await?;
+    let outcome = admin
+        .call(contract.id(), "add_group_member")
+        .args_json(json!({
+            "group_id": group_id,
+            "account_id": outsider.id(),
+            "role": "Treasurer"
+        }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure(), "Admin should not add a Treasurer");
+
+    let outcome = admin
+        .call(contract.id(), "create_group_invite")
+        .args_json(json!({
+            "group_id": group_id,
+            "post_group_invite": {
+                "expires_on": u64::MAX,
+                "max_uses": 1,
+                "role": "Treasurer"
+            }
+        }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure(), "Admin should not invite a Treasurer");
+
+    // Nor take the role away once the owner gave it
+    let outcome = owner
+        .call(contract.id(), "set_group_member_role")
+        .args_json(json!({
+            "group_id": group_id,
+            "account_id": member.id(),
+            "role": "Treasurer"
+        }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success(), "Owner should promote to Treasurer");
+
+    let outcome = admin
+        .call(contract.id(), "set_group_member_role")
+        .args_json(json!({
+            "group_id": group_id,
+            "account_id": member.id(),
+            "role": "Member"
+        }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure(), "Admin should not demote a Treasurer");
+
+    let role: Option<ApplicationRole> = contract
+        .view("get_user_role_in_group")
+        .args_json(json!({ "group_id": group_id, "account_id": member.id() }))
+        .await?
+        .json()?;
+    assert_eq!(role, Some(ApplicationRole::Treasurer));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_group_member_role() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let member = sandbox.dev_create_account().await?;
+    add_profile(&contract, &member, "member").await?;
+    let _ = member
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+
+    let outcome = owner
+        .call(contract.id(), "set_group_member_role")
+        .args_json(json!({
+            "group_id": group_id,
+            "account_id": member.id(),
+            "role": "Moderator"
+        }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success(), "Owner should promote members");
+
+    let role: Option<String> = contract
+        .view("get_user_role_in_group")
+        .args_json(json!({ "account_id": member.id(), "group_id": group_id }))
+        .await?
+        .json()?;
+    assert_eq!(role, Some("Moderator".to_string()));
+
+    // Ownership can't be handed out through a role change
+    let outcome = owner
+        .call(contract.id(), "set_group_member_role")
+        .args_json(json!({
+            "group_id": group_id,
+            "account_id": member.id(),
+            "role": "Owner"
+        }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure(), "Owner role should not be assignable");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_add_and_remove_group_member() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let member = sandbox.dev_create_account().await?;
+    add_profile(&contract, &member, "member").await?;
+
+    let outcome = owner
+        .call(contract.id(), "add_group_member")
+        .args_json(json!({ "group_id": group_id, "account_id": member.id() }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success(), "Owner should add members");
+
+    let user_groups: Vec<u32> = contract
+        .view("get_user_groups")
+        .args_json(json!({ "account_id": member.id() }))
+        .await?
+        .json()?;
+    assert_eq!(user_groups, vec![group_id]);
+
+    // Plain members can't kick the owner
+    let outcome = member
+        .call(contract.id(), "remove_group_member")
+        .args_json(json!({ "group_id": group_id, "account_id": owner.id() }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure(), "Member should not remove the owner");
+
+    let outcome = owner
+        .call(contract.id(), "remove_group_member")
+        .args_json(json!({ "group_id": group_id, "account_id": member.id() }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success(), "Owner should remove members");
+
+    let is_in_group: bool = contract
+        .view("is_user_in_group")
+        .args_json(json!({ "account_id": member.id(), "group_id": group_id }))
+        .await?
+        .json()?;
+    assert!(!is_in_group, "Member should be removed from the group");
+
+    let user_groups: Vec<u32> = contract
+        .view("get_user_groups")
+        .args_json(json!({ "account_id": member.id() }))
+        .await?
+        .json()?;
+    assert!(
+        user_groups.is_empty(),
+        "Profile should no longer list the group"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_group_members_page() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let joined = sandbox.dev_create_account().await?;
+    add_profile(&contract, &joined, "joined").await?;
+    let _ = joined
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+
+    let added = sandbox.dev_create_account().await?;
+    add_profile(&contract, &added, "added").await?;
+    let outcome = owner
+        .call(contract.id(), "add_group_member")
+        .args_json(json!({ "group_id": group_id, "account_id": added.id(), "role": "Moderator" }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    let members: Vec<MemberResponse> = contract
+        .view("get_group_members_page")
+        .args_json(json!({
+            "group_id": group_id,
+            "sort": "JoinedOn",
+            "order": "Desc",
+            "index": 0,
+            "limit": 2
+        }))
+        .await?
+        .json()?;
+    assert_eq!(members.len(), 2);
+    assert_eq!(members[0].account_id, *added.id());
+    assert_eq!(members[0].added_by.as_ref(), Some(owner.id()));
+    assert_eq!(members[1].account_id, *joined.id());
+    assert_eq!(members[1].added_by, None);
+
+    let moderators: Vec<MemberResponse> = contract
+        .view("get_group_members_page")
+        .args_json(json!({
+            "group_id": group_id,
+            "filter": { "role": "Moderator" },
+            "index": 0,
+            "limit": 10
+        }))
+        .await?
+        .json()?;
+    assert_eq!(moderators.len(), 1);
+    assert_eq!(moderators[0].account_id, *added.id());
+
+    // Without a sort members come ordered by account id, pages continue where the last ended
+    let mut account_ids = [owner.id().clone(), joined.id().clone(), added.id().clone()];
+    account_ids.sort();
+    for (index, account_id) in account_ids.iter().enumerate() {
+        let page: Vec<MemberResponse> = contract
+            .view("get_group_members_page")
+            .args_json(json!({ "group_id": group_id, "index": index, "limit": 1 }))
+            .await?
+            .json()?;
+        assert_eq!(page[0].account_id, *account_id);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_role_storage_order() {
+    // Stored roles must keep decoding to the same variant after an upgrade
+    let roles = [
+        ApplicationRole::Owner,
+        ApplicationRole::Member,
+        ApplicationRole::Admin,
+        ApplicationRole::Moderator,
+        ApplicationRole::Guest,
+        ApplicationRole::Banned,
+        ApplicationRole::Treasurer,
+    ];
+    for (position, role) in roles.iter().enumerate() {
+        assert_eq!(borsh::to_vec(role).unwrap(), vec![position as u8]);
+    }
+}
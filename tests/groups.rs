@@ -275,3 +275,356 @@ async fn test_get_groups_by_id() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_edit_group_unauthorized() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, user) = init().await?;
+
+    let _ = user
+        .call(contract.id(), "add_profile")
+        .args_json(json!({"post_profile": {
+            "username": "jassification",
+            "display_name": "Jas",
+            "first_name": "Jaswinder",
+            "last_name": "Singh",
+            "extra": "Test"
+        }}))
+        .transact()
+        .await?;
+
+    let group_id = user
+        .call(contract.id(), "add_group")
+        .args_json(json!({
+            "post_group": {
+                "name": "Test Group",
+                "description": "A test group",
+                "website": "https://example.com",
+                "image": "ipfs://example",
+                "banner_image": "ipfs://example_banner",
+                "matrix_space_id": "space123",
+                "tags": vec![1, 2, 3]
+            }
+        }))
+        .transact()
+        .await
+        .unwrap()
+        .json::<u32>()
+        .unwrap();
+
+    let member = sandbox.dev_create_account().await?;
+    let _ = member
+        .call(contract.id(), "add_profile")
+        .args_json(json!({"post_profile": {
+            "username": "testname",
+            "display_name": "Test",
+            "first_name": "Test",
+            "last_name": "Test",
+            "extra": "Test"
+        }}))
+        .transact()
+        .await?;
+    let _ = member
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+
+    // A plain member is not allowed to edit the group
+    let outcome = member
+        .call(contract.id(), "edit_group")
+        .args_json(json!({
+            "id": group_id,
+            "update_group": {
+                "name": "Defaced Group"
+            }
+        }))
+        .transact()
+        .await?;
+
+    assert!(outcome.is_failure(), "Member should not be able to edit");
+
+    let group = contract
+        .view("get_group")
+        .args_json(json!({"id": group_id}))
+        .await?
+        .json::<Option<GroupResponse>>()?;
+
+    assert_eq!(group.unwrap().name, "Test Group");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_delete_and_restore_group() -> Result<(), Box<dyn std::error::Error>> {
+    let (_, contract, user) = init().await?;
+
+    let _ = user
+        .call(contract.id(), "add_profile")
+        .args_json(json!({"post_profile": {
+            "username": "jassification",
+            "display_name": "Jas",
+            "first_name": "Jaswinder",
+            "last_name": "Singh",
+            "extra": "Test"
+        }}))
+        .transact()
+        .await?;
+
+    let group_id = user
+        .call(contract.id(), "add_group")
+        .args_json(json!({
+            "post_group": {
+                "name": "Test Group",
+                "description": "A test group",
+                "website": "https://example.com",
+                "image": "ipfs://example",
+                "banner_image": "ipfs://example_banner",
+                "matrix_space_id": "space123",
+                "tags": vec![1, 2, 3]
+            }
+        }))
+        .transact()
+        .await?
+        .json::<u32>()?;
+
+    let outcome = user
+        .call(contract.id(), "delete_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success(), "Owner should delete the group");
+
+    // Deleted groups are left out unless explicitly asked for
+    let groups = contract
+        .view("get_groups")
+        .args_json(json!({"index": 0, "limit": 10}))
+        .await?
+        .json::<Vec<GroupResponse>>()?;
+    assert!(groups.is_empty(), "Deleted group should not be listed");
+
+    let group = contract
+        .view("get_group")
+        .args_json(json!({"id": group_id, "include_deleted": true}))
+        .await?
+        .json::<Option<GroupResponse>>()?;
+    assert!(
+        group.unwrap().is_deleted,
+        "Group should be marked as deleted"
+    );
+
+    let user_groups = contract
+        .view("get_user_groups")
+        .args_json(json!({"account_id": user.id()}))
+        .await?
+        .json::<Vec<u32>>()?;
+    assert!(user_groups.is_empty(), "Deleted group should not be joined");
+
+    let is_in_group = contract
+        .view("is_user_in_group")
+        .args_json(json!({"account_id": user.id(), "group_id": group_id}))
+        .await?
+        .json::<bool>()?;
+    assert!(
+        !is_in_group,
+        "Membership of a deleted group should be hidden"
+    );
+
+    let role = contract
+        .view("get_user_role_in_group")
+        .args_json(json!({"account_id": user.id(), "group_id": group_id, "include_deleted": true}))
+        .await?
+        .json::<Option<String>>()?;
+    assert_eq!(role, Some("Owner".to_string()));
+
+    let outcome = user
+        .call(contract.id(), "restore_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success(), "Owner should restore the group");
+
+    let group = contract
+        .view("get_group")
+        .args_json(json!({"id": group_id}))
+        .await?
+        .json::<Option<GroupResponse>>()?;
+    assert!(group.is_some(), "Restored group should be visible again");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_purge_group() -> Result<(), Box<dyn std::error::Error>> {
+    let (_, contract, user) = init().await?;
+
+    let _ = user
+        .call(contract.id(), "add_profile")
+        .args_json(json!({"post_profile": {
+            "username": "jassification",
+            "display_name": "Jas",
+            "first_name": "Jaswinder",
+            "last_name": "Singh",
+            "extra": "Test"
+        }}))
+        .transact()
+        .await?;
+
+    let group_id = user
+        .call(contract.id(), "add_group")
+        .args_json(json!({
+            "post_group": {
+                "name": "Test Group",
+                "description": "A test group",
+                "website": "https://example.com",
+                "image": "ipfs://example",
+                "banner_image": "ipfs://example_banner",
+                "matrix_space_id": "space123",
+                "tags": vec![1, 2, 3]
+            }
+        }))
+        .transact()
+        .await?
+        .json::<u32>()?;
+
+    // Only deleted groups can be purged
+    let outcome = contract
+        .call("purge_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure(), "Active group should not be purged");
+
+    let _ = user
+        .call(contract.id(), "delete_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+
+    // Purging is reserved to the contract account
+    let outcome = user
+        .call(contract.id(), "purge_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure(), "Users should not purge groups");
+
+    let outcome = contract
+        .call("purge_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success(), "Contract should purge the group");
+
+    let group = contract
+        .view("get_group")
+        .args_json(json!({"id": group_id, "include_deleted": true}))
+        .await?
+        .json::<Option<GroupResponse>>()?;
+    assert!(group.is_none(), "Purged group should be gone");
+
+    let user_groups: Vec<u32> = contract
+        .view("get_user_groups")
+        .args_json(json!({ "account_id": user.id() }))
+        .await?
+        .json()?;
+    assert!(
+        user_groups.is_empty(),
+        "Profile should no longer list the group"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unique_group_names_and_slugs() -> Result<(), Box<dyn std::error::Error>> {
+    let (_, contract, user) = init().await?;
+
+    let _ = user
+        .call(contract.id(), "add_profile")
+        .args_json(json!({"post_profile": {
+            "username": "jassification",
+            "display_name": "Jas",
+            "first_name": "Jaswinder",
+            "last_name": "Singh",
+            "extra": "Test"
+        }}))
+        .transact()
+        .await?;
+
+    let post_group = |name: &str| {
+        json!({
+            "post_group": {
+                "name": name,
+                "description": "A test group",
+                "website": "https://example.com",
+                "image": "ipfs://example",
+                "banner_image": "ipfs://example_banner",
+                "matrix_space_id": "space123",
+                "tags": vec![1, 2, 3]
+            }
+        })
+    };
+
+    let group_id: u32 = user
+        .call(contract.id(), "add_group")
+        .args_json(post_group("Rust Builders"))
+        .transact()
+        .await?
+        .json()?;
+
+    // Same name in another case or spacing, and a name with the same slug
+    for name in ["rust  BUILDERS", "Rust-Builders!"] {
+        let outcome = user
+            .call(contract.id(), "add_group")
+            .args_json(post_group(name))
+            .transact()
+            .await?;
+        assert!(outcome.is_failure(), "{} should be taken", name);
+    }
+
+    let other_id: u32 = user
+        .call(contract.id(), "add_group")
+        .args_json(post_group("Other Group"))
+        .transact()
+        .await?
+        .json()?;
+    let outcome = user
+        .call(contract.id(), "edit_group")
+        .args_json(json!({ "id": other_id, "update_group": { "name": "RUST BUILDERS" } }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure());
+
+    let group = contract
+        .view("get_group_by_slug")
+        .args_json(json!({ "slug": "rust-builders" }))
+        .await?
+        .json::<Option<GroupResponse>>()?;
+    assert_eq!(group.map(|group| group.id), Some(group_id));
+
+    // Renaming frees the old name and slug
+    let outcome = user
+        .call(contract.id(), "edit_group")
+        .args_json(json!({ "id": group_id, "update_group": { "name": "Rust Crafters" } }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    let group = contract
+        .view("get_group_by_name")
+        .args_json(json!({ "name": "rust crafters" }))
+        .await?
+        .json::<Option<GroupResponse>>()?;
+    assert_eq!(
+        group.map(|group| group.slug),
+        Some("rust-crafters".to_string())
+    );
+
+    let group = contract
+        .view("get_group_by_slug")
+        .args_json(json!({ "slug": "rust-builders" }))
+        .await?
+        .json::<Option<GroupResponse>>()?;
+    assert!(group.is_none());
+
+    Ok(())
+}
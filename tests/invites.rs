@@ -0,0 +1,152 @@
+mod common;
+
+use cat_near_contract::models::invites::GroupInvite;
+use common::{add_group, add_profile, init};
+use near_sdk::serde_json::{json, Value};
+
+const FAR_FUTURE: u64 = 4_000_000_000_000_000_000;
+
+#[tokio::test]
+async fn test_join_group_with_invite() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let _ = owner
+        .call(contract.id(), "edit_group")
+        .args_json(json!({
+            "id": group_id,
+            "update_group": { "join_policy": "InviteOnly" }
+        }))
+        .transact()
+        .await?;
+
+    let code: String = owner
+        .call(contract.id(), "create_group_invite")
+        .args_json(json!({
+            "group_id": group_id,
+            "post_group_invite": {
+                "expires_on": FAR_FUTURE,
+                "max_uses": 1,
+                "role": "Moderator",
+                "label": "launch"
+            }
+        }))
+        .transact()
+        .await?
+        .json()?;
+
+    let invitee = sandbox.dev_create_account().await?;
+    add_profile(&contract, &invitee, "invitee").await?;
+
+    let outcome = invitee
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(
+        outcome.is_failure(),
+        "Invite only group should not be joined directly"
+    );
+
+    let outcome = invitee
+        .call(contract.id(), "join_group_with_invite")
+        .args_json(json!({ "code": code }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success(), "Invitee should join with the code");
+
+    let role: Option<String> = contract
+        .view("get_user_role_in_group")
+        .args_json(json!({ "account_id": invitee.id(), "group_id": group_id }))
+        .await?
+        .json()?;
+    assert_eq!(
+        role,
+        Some("Moderator".to_string()),
+        "Invite role should be assigned"
+    );
+
+    // Views only expose the hash of the code
+    let invites: Value = contract
+        .view("get_group_invites")
+        .args_json(json!({ "group_id": group_id, "include_inactive": true }))
+        .await?
+        .json()?;
+    assert!(
+        !invites.to_string().contains(&code),
+        "Code should not be readable"
+    );
+    let invite_id = invites[0]["id"].as_str().unwrap();
+
+    let invite: Option<GroupInvite> = contract
+        .view("get_group_invite")
+        .args_json(json!({ "invite_id": invite_id }))
+        .await?
+        .json()?;
+    let invite = invite.unwrap();
+    assert_eq!(invite.uses, 1);
+    assert_eq!(invite.redemptions[0].account_id, *invitee.id());
+
+    // The code was single use
+    let other = sandbox.dev_create_account().await?;
+    add_profile(&contract, &other, "other").await?;
+    let outcome = other
+        .call(contract.id(), "join_group_with_invite")
+        .args_json(json!({ "code": code }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure(), "Used up invite should be refused");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_revoke_group_invite() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let code: String = owner
+        .call(contract.id(), "create_group_invite")
+        .args_json(json!({
+            "group_id": group_id,
+            "post_group_invite": { "expires_on": FAR_FUTURE, "max_uses": 10 }
+        }))
+        .transact()
+        .await?
+        .json()?;
+
+    let invites: Vec<GroupInvite> = contract
+        .view("get_group_invites")
+        .args_json(json!({ "group_id": group_id }))
+        .await?
+        .json()?;
+    assert_eq!(invites.len(), 1, "Invite should be listed");
+    assert_ne!(invites[0].id, code, "Invite should be kept by its hash");
+
+    let outcome = owner
+        .call(contract.id(), "revoke_group_invite")
+        .args_json(json!({ "invite_id": invites[0].id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success(), "Owner should revoke the invite");
+
+    let invites: Vec<GroupInvite> = contract
+        .view("get_group_invites")
+        .args_json(json!({ "group_id": group_id }))
+        .await?
+        .json()?;
+    assert!(invites.is_empty(), "Revoked invite should not be listed");
+
+    let invitee = sandbox.dev_create_account().await?;
+    add_profile(&contract, &invitee, "invitee").await?;
+    let outcome = invitee
+        .call(contract.id(), "join_group_with_invite")
+        .args_json(json!({ "code": code }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure(), "Revoked invite should be refused");
+
+    Ok(())
+}
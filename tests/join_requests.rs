@@ -0,0 +1,178 @@
+mod common;
+
+use cat_near_contract::models::join_requests::JoinRequest;
+use cat_near_contract::models::rewards::Rewards;
+use common::{add_group, add_profile, init};
+use near_sdk::serde_json::json;
+use near_sdk::AccountId;
+
+#[tokio::test]
+async fn test_approve_and_reject_join_requests() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let _ = owner
+        .call(contract.id(), "edit_group")
+        .args_json(json!({
+            "id": group_id,
+            "update_group": { "join_policy": "RequestToJoin" }
+        }))
+        .transact()
+        .await?;
+
+    let mut applicants = vec![];
+    for i in 0..3 {
+        let applicant = sandbox.dev_create_account().await?;
+        add_profile(&contract, &applicant, &format!("applicant{}", i)).await?;
+
+        // Joining directly isn't possible anymore
+        let outcome = applicant
+            .call(contract.id(), "join_group")
+            .args_json(json!({ "group_id": group_id }))
+            .transact()
+            .await?;
+        assert!(outcome.is_failure(), "Join should require a request");
+
+        let outcome = applicant
+            .call(contract.id(), "request_to_join_group")
+            .args_json(json!({ "group_id": group_id, "message": "Let me in" }))
+            .transact()
+            .await?;
+        assert!(outcome.is_success(), "Request should be added");
+        applicants.push(applicant);
+    }
+
+    let requests: Vec<JoinRequest> = contract
+        .view("get_group_join_requests")
+        .args_json(json!({ "group_id": group_id }))
+        .await?
+        .json()?;
+    assert_eq!(requests.len(), 3, "Three requests should be pending");
+
+    let requests: Vec<JoinRequest> = contract
+        .view("get_user_join_requests")
+        .args_json(json!({ "account_id": applicants[0].id() }))
+        .await?
+        .json()?;
+    assert_eq!(requests.len(), 1, "Applicant should see their request");
+
+    // Applicants can't approve themselves
+    let outcome = applicants[0]
+        .call(contract.id(), "approve_join_requests")
+        .args_json(json!({ "group_id": group_id, "account_ids": [applicants[0].id()] }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure(), "Applicant should not approve");
+
+    let approved: Vec<AccountId> = owner
+        .call(contract.id(), "approve_join_requests")
+        .args_json(json!({
+            "group_id": group_id,
+            "account_ids": [applicants[0].id(), applicants[1].id()]
+        }))
+        .transact()
+        .await?
+        .json()?;
+    assert_eq!(approved.len(), 2, "Two requests should be approved");
+
+    let rejected: Vec<AccountId> = owner
+        .call(contract.id(), "reject_join_requests")
+        .args_json(json!({ "group_id": group_id, "account_ids": [applicants[2].id()] }))
+        .transact()
+        .await?
+        .json()?;
+    assert_eq!(rejected.len(), 1, "One request should be rejected");
+
+    for (applicant, expected) in applicants.iter().zip([true, true, false]) {
+        let is_in_group: bool = contract
+            .view("is_user_in_group")
+            .args_json(json!({ "account_id": applicant.id(), "group_id": group_id }))
+            .await?
+            .json()?;
+        assert_eq!(is_in_group, expected);
+    }
+
+    let rewards: Rewards = contract
+        .view("get_rewards")
+        .args_json(json!({ "account_id": applicants[0].id() }))
+        .await?
+        .json()?;
+    assert_eq!(
+        rewards.points, 10,
+        "Approved member should get the join reward"
+    );
+
+    let requests: Vec<JoinRequest> = contract
+        .view("get_group_join_requests")
+        .args_json(json!({ "group_id": group_id }))
+        .await?
+        .json()?;
+    assert!(requests.is_empty(), "No requests should be pending");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_approve_join_requests_in_full_group() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let _ = owner
+        .call(contract.id(), "edit_group")
+        .args_json(json!({
+            "id": group_id,
+            "update_group": { "join_policy": "RequestToJoin", "max_members": 2 }
+        }))
+        .transact()
+        .await?;
+
+    let mut applicants = vec![];
+    for i in 0..2 {
+        let applicant = sandbox.dev_create_account().await?;
+        add_profile(&contract, &applicant, &format!("applicant{}", i)).await?;
+        let _ = applicant
+            .call(contract.id(), "request_to_join_group")
+            .args_json(json!({ "group_id": group_id }))
+            .transact()
+            .await?;
+        applicants.push(applicant);
+    }
+
+    // Only one seat is left next to the owner
+    let approved: Vec<AccountId> = owner
+        .call(contract.id(), "approve_join_requests")
+        .args_json(json!({
+            "group_id": group_id,
+            "account_ids": [applicants[0].id(), applicants[1].id()]
+        }))
+        .transact()
+        .await?
+        .json()?;
+    assert_eq!(approved, vec![applicants[0].id().clone()]);
+
+    let requests: Vec<JoinRequest> = contract
+        .view("get_group_join_requests")
+        .args_json(json!({ "group_id": group_id }))
+        .await?
+        .json()?;
+    assert_eq!(requests.len(), 1, "Request of a full group should stay");
+    assert_eq!(requests[0].account_id, *applicants[1].id());
+
+    // Once a seat frees up the same request can be approved
+    let _ = applicants[0]
+        .call(contract.id(), "leave_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    let approved: Vec<AccountId> = owner
+        .call(contract.id(), "approve_join_requests")
+        .args_json(json!({ "group_id": group_id, "account_ids": [applicants[1].id()] }))
+        .transact()
+        .await?
+        .json()?;
+    assert_eq!(approved, vec![applicants[1].id().clone()]);
+
+    Ok(())
+}
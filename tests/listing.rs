@@ -0,0 +1,163 @@
+mod common;
+
+use cat_near_contract::models::groups::GroupPage;
+use common::{add_profile, init};
+use near_sdk::serde_json::json;
+use near_workspaces::{Account, Contract};
+
+async fn add_named_group(
+    contract: &Contract,
+    owner: &Account,
+    name: &str,
+) -> Result<u32, Box<dyn std::error::Error>> {
+    let group_id = owner
+        .call(contract.id(), "add_group")
+        .args_json(json!({
+            "post_group": {
+                "name": name,
+                "description": "A test group",
+                "website": "https://example.com",
+                "image": "ipfs://example",
+                "banner_image": "ipfs://example_banner",
+                "matrix_space_id": "space123",
+                "tags": vec![1, 2, 3]
+            }
+        }))
+        .transact()
+        .await?
+        .json::<u32>()?;
+    Ok(group_id)
+}
+
+#[tokio::test]
+async fn test_get_groups_page_with_cursor() -> Result<(), Box<dyn std::error::Error>> {
+    let (_, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+
+    let mut ids = vec![];
+    for i in 0..3 {
+        ids.push(add_named_group(&contract, &owner, &format!("Group {}", i)).await?);
+    }
+
+    // Newest first
+    let page: GroupPage = contract
+        .view("get_groups_page")
+        .args_json(json!({ "order": "Desc", "limit": 2 }))
+        .await?
+        .json()?;
+    let page_ids: Vec<u32> = page.groups.iter().map(|group| group.id).collect();
+    assert_eq!(page_ids, vec![ids[2], ids[1]]);
+    let cursor = page.next_cursor.expect("There should be a next page");
+
+    // A group added in between does not shift the next page
+    add_named_group(&contract, &owner, "Group 3").await?;
+
+    let page: GroupPage = contract
+        .view("get_groups_page")
+        .args_json(json!({ "order": "Desc", "cursor": cursor, "limit": 2 }))
+        .await?
+        .json()?;
+    let page_ids: Vec<u32> = page.groups.iter().map(|group| group.id).collect();
+    assert_eq!(page_ids, vec![ids[0]]);
+    assert!(page.next_cursor.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_groups_page_by_members_count() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let small = add_named_group(&contract, &owner, "Small Group").await?;
+    let large = add_named_group(&contract, &owner, "Large Group").await?;
+
+    let member = sandbox.dev_create_account().await?;
+    add_profile(&contract, &member, "member").await?;
+    let _ = member
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": large }))
+        .transact()
+        .await?;
+
+    let page: GroupPage = contract
+        .view("get_groups_page")
+        .args_json(json!({
+            "filter": { "owner": owner.id(), "tag": 2 },
+            "sort": "MembersCount",
+            "order": "Desc",
+            "limit": 10
+        }))
+        .await?
+        .json()?;
+    let page_ids: Vec<u32> = page.groups.iter().map(|group| group.id).collect();
+    assert_eq!(page_ids, vec![large, small]);
+
+    let page: GroupPage = contract
+        .view("get_groups_page")
+        .args_json(json!({ "filter": { "owner": member.id() }, "limit": 10 }))
+        .await?
+        .json()?;
+    assert!(page.groups.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_groups_page_follows_updates() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let first = add_named_group(&contract, &owner, "First Group").await?;
+    let second = add_named_group(&contract, &owner, "Second Group").await?;
+
+    let outcome = owner
+        .call(contract.id(), "edit_group")
+        .args_json(json!({
+            "id": first,
+            "update_group": { "description": "Edited after the second group" }
+        }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    let page: GroupPage = contract
+        .view("get_groups_page")
+        .args_json(json!({ "sort": "UpdatedOn", "order": "Desc", "limit": 1 }))
+        .await?
+        .json()?;
+    assert_eq!(page.groups[0].id, first);
+    let cursor = page.next_cursor.expect("There should be a next page");
+
+    let page: GroupPage = contract
+        .view("get_groups_page")
+        .args_json(json!({ "sort": "UpdatedOn", "order": "Desc", "cursor": cursor, "limit": 1 }))
+        .await?
+        .json()?;
+    let page_ids: Vec<u32> = page.groups.iter().map(|group| group.id).collect();
+    assert_eq!(page_ids, vec![second]);
+    assert!(page.next_cursor.is_none());
+
+    // Once the member left both groups count one member and the id breaks the tie
+    let member = sandbox.dev_create_account().await?;
+    add_profile(&contract, &member, "member").await?;
+    for call in ["join_group", "leave_group"] {
+        let outcome = member
+            .call(contract.id(), call)
+            .args_json(json!({ "group_id": second }))
+            .transact()
+            .await?;
+        assert!(outcome.is_success());
+    }
+
+    let page: GroupPage = contract
+        .view("get_groups_page")
+        .args_json(json!({ "sort": "MembersCount", "order": "Desc", "limit": 10 }))
+        .await?
+        .json()?;
+    let page_ids: Vec<u32> = page.groups.iter().map(|group| group.id).collect();
+    assert_eq!(page_ids, vec![second, first]);
+    for group in &page.groups {
+        assert_eq!(group.members_count, 1);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+mod common;
+
+use cat_near_contract::models::groups::GroupResponse;
+use common::{add_profile, init};
+use near_sdk::serde_json::{json, Value};
+use near_workspaces::{Account, Contract};
+
+async fn add_located_group(
+    contract: &Contract,
+    owner: &Account,
+    name: &str,
+    location: Value,
+) -> Result<near_workspaces::result::ExecutionFinalResult, Box<dyn std::error::Error>> {
+    Ok(owner
+        .call(contract.id(), "add_group")
+        .args_json(json!({
+            "post_group": {
+                "name": name,
+                "description": "A local group",
+                "website": "https://example.com",
+                "image": "ipfs://example",
+                "banner_image": "ipfs://example_banner",
+                "matrix_space_id": "space123",
+                "tags": vec![1, 2, 3],
+                "location": location
+            }
+        }))
+        .transact()
+        .await?)
+}
+
+async fn group_ids(
+    contract: &Contract,
+    view: &str,
+    args: Value,
+) -> Result<Vec<u32>, Box<dyn std::error::Error>> {
+    let groups: Vec<GroupResponse> = contract.view(view).args_json(args).await?.json()?;
+    Ok(groups.into_iter().map(|group| group.id).collect())
+}
+
+#[tokio::test]
+async fn test_groups_by_location() -> Result<(), Box<dyn std::error::Error>> {
+    let (_, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+
+    let berlin: u32 = add_located_group(
+        &contract,
+        &owner,
+        "Berlin Meetup",
+        json!({ "city": "Berlin", "state_or_province": "Berlin", "country": "Germany", "geohash": "u33dc0" }),
+    )
+    .await?
+    .json()?;
+    let munich: u32 = add_located_group(
+        &contract,
+        &owner,
+        "Munich Meetup",
+        json!({ "city": "Munich", "state_or_province": "Bavaria", "country": "germany", "geohash": "u281z7" }),
+    )
+    .await?
+    .json()?;
+
+    let outcome = add_located_group(
+        &contract,
+        &owner,
+        "Nowhere Meetup",
+        json!({ "city": "", "state_or_province": "", "country": "", "geohash": "not-a-hash" }),
+    )
+    .await?;
+    assert!(outcome.is_failure());
+
+    let args = json!({ "country": "GERMANY", "index": 0, "limit": 10 });
+    assert_eq!(
+        group_ids(&contract, "get_groups_by_country", args).await?,
+        vec![berlin, munich]
+    );
+
+    let args =
+        json!({ "country": "Germany", "state_or_province": "Bavaria", "index": 0, "limit": 10 });
+    assert_eq!(
+        group_ids(&contract, "get_groups_by_region", args).await?,
+        vec![munich]
+    );
+
+    let args = json!({ "prefix": "u3", "index": 0, "limit": 10 });
+    assert_eq!(
+        group_ids(&contract, "get_groups_by_geohash", args).await?,
+        vec![berlin]
+    );
+
+    let args = json!({ "prefix": "u", "index": 0, "limit": 10 });
+    assert_eq!(
+        group_ids(&contract, "get_groups_by_geohash", args).await?,
+        vec![berlin, munich]
+    );
+
+    // Moving a group moves it in the index
+    let outcome = owner
+        .call(contract.id(), "edit_group")
+        .args_json(json!({
+            "id": munich,
+            "update_group": {
+                "location": { "city": "Vienna", "state_or_province": "Vienna", "country": "Austria", "geohash": "u2edk8" }
+            }
+        }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    let args = json!({ "country": "Germany", "index": 0, "limit": 10 });
+    assert_eq!(
+        group_ids(&contract, "get_groups_by_country", args).await?,
+        vec![berlin]
+    );
+
+    let args = json!({ "country": "Austria", "index": 0, "limit": 10 });
+    assert_eq!(
+        group_ids(&contract, "get_groups_by_country", args).await?,
+        vec![munich]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,152 @@
+mod common;
+
+use cat_near_contract::models::groups::GroupResponse;
+use cat_near_contract::models::members::MatrixMember;
+use common::{add_group, add_profile, init};
+use near_sdk::serde_json::json;
+use near_sdk::AccountId;
+
+#[tokio::test]
+async fn test_group_matrix_members() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let member = sandbox.dev_create_account().await?;
+    add_profile(&contract, &member, "member").await?;
+    let _ = member
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+
+    let outcome = member
+        .call(contract.id(), "set_matrix_user_id")
+        .args_json(json!({ "matrix_user_id": "not a matrix id" }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure());
+
+    let outcome = member
+        .call(contract.id(), "set_matrix_user_id")
+        .args_json(json!({ "matrix_user_id": "@member:matrix.org" }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    // A claim isn't linked until the verifier confirms it
+    let matrix_members: Vec<MatrixMember> = contract
+        .view("get_group_matrix_members")
+        .args_json(json!({ "group_id": group_id, "index": 0, "limit": 10 }))
+        .await?
+        .json()?;
+    assert!(matrix_members.is_empty());
+
+    let verifier = sandbox.dev_create_account().await?;
+    let _ = contract
+        .as_account()
+        .call(contract.id(), "set_matrix_verifier")
+        .args_json(json!({ "matrix_verifier": verifier.id() }))
+        .transact()
+        .await?;
+    let outcome = owner
+        .call(contract.id(), "verify_matrix_user_id")
+        .args_json(json!({ "account_id": member.id(), "matrix_user_id": "@member:matrix.org" }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure(), "Only the verifier should verify");
+    let outcome = verifier
+        .call(contract.id(), "verify_matrix_user_id")
+        .args_json(json!({ "account_id": member.id(), "matrix_user_id": "@member:matrix.org" }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    // Only members with a linked id are listed
+    let matrix_members: Vec<MatrixMember> = contract
+        .view("get_group_matrix_members")
+        .args_json(json!({ "group_id": group_id, "index": 0, "limit": 10 }))
+        .await?
+        .json()?;
+    assert_eq!(matrix_members.len(), 1);
+    assert_eq!(matrix_members[0].account_id, *member.id());
+    assert_eq!(matrix_members[0].matrix_user_id, "@member:matrix.org");
+
+    // A linked id can't be claimed by another account
+    let other = sandbox.dev_create_account().await?;
+    add_profile(&contract, &other, "other").await?;
+    let outcome = other
+        .call(contract.id(), "set_matrix_user_id")
+        .args_json(json!({ "matrix_user_id": "@member:matrix.org" }))
+        .transact()
+        .await?;
+    assert!(
+        outcome.is_failure(),
+        "Linked id should not be claimed twice"
+    );
+
+    let linked_to: Option<AccountId> = contract
+        .view("get_account_by_matrix_user_id")
+        .args_json(json!({ "matrix_user_id": "@member:matrix.org" }))
+        .await?
+        .json()?;
+    assert_eq!(linked_to.as_ref(), Some(member.id()));
+
+    // Unlinking frees the id
+    let _ = member
+        .call(contract.id(), "set_matrix_user_id")
+        .args_json(json!({ "matrix_user_id": null }))
+        .transact()
+        .await?;
+    let outcome = other
+        .call(contract.id(), "set_matrix_user_id")
+        .args_json(json!({ "matrix_user_id": "@member:matrix.org" }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_matrix_space_id() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let admin = sandbox.dev_create_account().await?;
+    add_profile(&contract, &admin, "admin").await?;
+    let _ = owner
+        .call(contract.id(), "add_group_member")
+        .args_json(json!({ "group_id": group_id, "account_id": admin.id(), "role": "Admin" }))
+        .transact()
+        .await?;
+
+    // Admins can edit the group but not move its Matrix space
+    let outcome = admin
+        .call(contract.id(), "edit_group")
+        .args_json(
+            json!({ "id": group_id, "update_group": { "matrix_space_id": "!moved:matrix.org" } }),
+        )
+        .transact()
+        .await?;
+    assert!(outcome.is_failure());
+
+    let outcome = owner
+        .call(contract.id(), "edit_group")
+        .args_json(
+            json!({ "id": group_id, "update_group": { "matrix_space_id": "!moved:matrix.org" } }),
+        )
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    let group: GroupResponse = contract
+        .view("get_group")
+        .args_json(json!({ "id": group_id }))
+        .await?
+        .json()?;
+    assert_eq!(group.matrix_space_id, "!moved:matrix.org");
+
+    Ok(())
+}
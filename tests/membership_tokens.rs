@@ -0,0 +1,98 @@
+mod common;
+
+use cat_near_contract::models::sbt::{OwnedSbtToken, SbtToken};
+use common::{add_profile, init};
+use near_sdk::serde_json::json;
+use near_sdk::AccountId;
+
+#[tokio::test]
+async fn test_membership_token_minted_and_revoked() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id: u32 = owner
+        .call(contract.id(), "add_group")
+        .args_json(json!({
+            "post_group": {
+                "name": "Token Group",
+                "description": "A test group",
+                "website": "https://example.com",
+                "image": "ipfs://example",
+                "banner_image": "ipfs://example_banner",
+                "matrix_space_id": "space123",
+                "tags": vec![1, 2, 3],
+                "mint_membership_tokens": true
+            }
+        }))
+        .transact()
+        .await?
+        .json()?;
+
+    let member = sandbox.dev_create_account().await?;
+    add_profile(&contract, &member, "member").await?;
+    let outcome = member
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+    assert!(outcome
+        .logs()
+        .iter()
+        .any(|log| log.contains("\"standard\":\"nep393\"")));
+
+    let tokens: Vec<(AccountId, Vec<OwnedSbtToken>)> = contract
+        .view("sbt_tokens_by_owner")
+        .args_json(json!({ "account": member.id() }))
+        .await?
+        .json()?;
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].0, *contract.id());
+    assert_eq!(tokens[0].1.len(), 1);
+    assert_eq!(tokens[0].1[0].metadata.class, group_id as u64 + 1);
+
+    let supply: u64 = contract
+        .view("sbt_supply")
+        .args_json(json!({ "issuer": contract.id() }))
+        .await?
+        .json()?;
+    assert_eq!(supply, 1);
+
+    let token = tokens[0].1[0].token;
+    let issued: Vec<SbtToken> = contract
+        .view("sbt_tokens")
+        .args_json(json!({ "issuer": contract.id() }))
+        .await?
+        .json()?;
+    assert!(issued
+        .iter()
+        .any(|issued| issued.token == token && issued.owner == *member.id()));
+
+    let outcome = member
+        .call(contract.id(), "leave_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    let supply: u64 = contract
+        .view("sbt_supply_by_owner")
+        .args_json(json!({ "account": member.id(), "issuer": contract.id() }))
+        .await?
+        .json()?;
+    assert_eq!(supply, 0);
+
+    let revoked: bool = contract
+        .view("sbt_revoked")
+        .args_json(json!({ "issuer": contract.id(), "token": token }))
+        .await?
+        .json()?;
+    assert!(revoked);
+    let issued: Vec<SbtToken> = contract
+        .view("sbt_tokens")
+        .args_json(json!({ "issuer": contract.id(), "from_token": token }))
+        .await?
+        .json()?;
+    assert!(issued.is_empty());
+
+    Ok(())
+}
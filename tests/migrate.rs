@@ -0,0 +1,119 @@
+mod common;
+
+use cat_near_contract::models::groups::GroupResponse;
+use common::add_profile;
+use near_sdk::serde_json::json;
+use near_workspaces::Account;
+use std::collections::HashMap;
+
+async fn add_group(
+    contract: &near_workspaces::Contract,
+    owner: &Account,
+    name: &str,
+) -> Result<u32, Box<dyn std::error::Error>> {
+    let group_id = owner
+        .call(contract.id(), "add_group")
+        .args_json(json!({
+            "post_group": {
+                "name": name,
+                "description": "A group of the first release",
+                "website": "https://example.com",
+                "image": "ipfs://example",
+                "banner_image": "ipfs://example_banner",
+                "matrix_space_id": "space123",
+                "tags": vec![1, 2, 3]
+            }
+        }))
+        .transact()
+        .await?
+        .json()?;
+    Ok(group_id)
+}
+
+#[tokio::test]
+async fn test_migrate_first_release_state() -> Result<(), Box<dyn std::error::Error>> {
+    let sandbox = near_workspaces::sandbox().await?;
+    let first_release_wasm =
+        near_workspaces::compile_project("./tests/contracts/first_release").await?;
+    let contract = sandbox.dev_deploy(&first_release_wasm).await?;
+    let outcome = contract.call("new").args_json(json!({})).transact().await?;
+    assert!(outcome.is_success());
+
+    let owner = sandbox.dev_create_account().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let member = sandbox.dev_create_account().await?;
+    add_profile(&contract, &member, "member").await?;
+
+    // The first release allowed several groups with the same name
+    let first_id = add_group(&contract, &owner, "Climbers").await?;
+    let second_id = add_group(&contract, &owner, "Climbers").await?;
+    for group_id in [first_id, second_id] {
+        let outcome = member
+            .call(contract.id(), "join_group")
+            .args_json(json!({ "group_id": group_id }))
+            .transact()
+            .await?;
+        assert!(outcome.is_success());
+    }
+
+    let contract_wasm = near_workspaces::compile_project("./").await?;
+    let contract = contract
+        .as_account()
+        .deploy(&contract_wasm)
+        .await?
+        .into_result()?;
+    let outcome = contract
+        .call("migrate")
+        .args_json(json!({}))
+        .max_gas()
+        .transact()
+        .await?;
+    assert!(outcome.is_success(), "{:?}", outcome.failures());
+
+    for group_id in [first_id, second_id] {
+        let members: HashMap<String, String> = contract
+            .view("get_group_members")
+            .args_json(json!({ "group_id": group_id }))
+            .await?
+            .json()?;
+        assert_eq!(members.len(), 2);
+        assert_eq!(members[owner.id().as_str()], "Owner");
+        assert_eq!(members[member.id().as_str()], "Member");
+
+        let group: Option<GroupResponse> = contract
+            .view("get_group")
+            .args_json(json!({ "id": group_id }))
+            .await?
+            .json()?;
+        assert_eq!(group.unwrap().members_count, 2);
+    }
+
+    let joined_groups: Vec<u32> = contract
+        .view("get_user_groups")
+        .args_json(json!({ "account_id": member.id() }))
+        .await?
+        .json()?;
+    assert_eq!(joined_groups, vec![first_id, second_id]);
+
+    // The older group keeps the shared name
+    let group: Option<GroupResponse> = contract
+        .view("get_group_by_name")
+        .args_json(json!({ "name": "Climbers" }))
+        .await?
+        .json()?;
+    assert_eq!(group.unwrap().id, first_id);
+
+    // Migrated state accepts the new endpoints
+    let outcome = owner
+        .call(contract.id(), "set_group_member_role")
+        .args_json(json!({
+            "group_id": second_id,
+            "account_id": member.id(),
+            "role": "Moderator"
+        }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    Ok(())
+}
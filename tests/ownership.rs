@@ -0,0 +1,209 @@
+mod common;
+
+use cat_near_contract::models::groups::GroupResponse;
+use cat_near_contract::models::ownership_transfer::OwnershipTransfer;
+use common::{add_group, add_profile, init};
+use near_sdk::serde_json::json;
+use near_workspaces::{Account, Contract};
+
+async fn join_group(
+    contract: &Contract,
+    user: &Account,
+    group_id: u32,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let _ = user
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    Ok(())
+}
+
+async fn get_role(
+    contract: &Contract,
+    user: &Account,
+    group_id: u32,
+) -> Result<Option<String>, Box<dyn std::error::Error>> {
+    let role = contract
+        .view("get_user_role_in_group")
+        .args_json(json!({ "account_id": user.id(), "group_id": group_id }))
+        .await?
+        .json()?;
+    Ok(role)
+}
+
+#[tokio::test]
+async fn test_accept_ownership_transfer() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let member = sandbox.dev_create_account().await?;
+    add_profile(&contract, &member, "member").await?;
+    join_group(&contract, &member, group_id).await?;
+
+    let outcome = owner
+        .call(contract.id(), "propose_ownership_transfer")
+        .args_json(json!({
+            "group_id": group_id,
+            "new_owner": member.id(),
+            "demote_to": "Moderator"
+        }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success(), "Owner should propose a transfer");
+
+    let pending: Vec<OwnershipTransfer> = contract
+        .view("get_pending_ownership_transfers")
+        .args_json(json!({ "account_id": member.id() }))
+        .await?
+        .json()?;
+    assert_eq!(pending.len(), 1, "Transfer should be pending");
+
+    let outcome = member
+        .call(contract.id(), "accept_ownership_transfer")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success(), "New owner should accept the transfer");
+
+    let group: Option<GroupResponse> = contract
+        .view("get_group")
+        .args_json(json!({ "id": group_id }))
+        .await?
+        .json()?;
+    assert_eq!(group.unwrap().owner, *member.id());
+    assert_eq!(
+        get_role(&contract, &member, group_id).await?,
+        Some("Owner".to_string())
+    );
+    assert_eq!(
+        get_role(&contract, &owner, group_id).await?,
+        Some("Moderator".to_string())
+    );
+
+    let pending: Option<OwnershipTransfer> = contract
+        .view("get_ownership_transfer")
+        .args_json(json!({ "group_id": group_id }))
+        .await?
+        .json()?;
+    assert!(pending.is_none(), "Transfer should no longer be pending");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_reject_and_cancel_ownership_transfer() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let member = sandbox.dev_create_account().await?;
+    add_profile(&contract, &member, "member").await?;
+    join_group(&contract, &member, group_id).await?;
+
+    let outcome = owner
+        .call(contract.id(), "propose_ownership_transfer")
+        .args_json(json!({ "group_id": group_id, "new_owner": member.id() }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success(), "Owner should propose a transfer");
+
+    // Only the proposed owner can accept
+    let outcome = owner
+        .call(contract.id(), "accept_ownership_transfer")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure(), "Proposer should not accept");
+
+    let outcome = member
+        .call(contract.id(), "reject_ownership_transfer")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success(), "Proposed owner should reject");
+
+    let outcome = owner
+        .call(contract.id(), "propose_ownership_transfer")
+        .args_json(json!({ "group_id": group_id, "new_owner": member.id() }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success(), "Owner should propose again");
+
+    let outcome = owner
+        .call(contract.id(), "cancel_ownership_transfer")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success(), "Owner should cancel the transfer");
+
+    let outcome = member
+        .call(contract.id(), "accept_ownership_transfer")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure(), "Cancelled transfer can't be accepted");
+    assert_eq!(
+        get_role(&contract, &owner, group_id).await?,
+        Some("Owner".to_string())
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_owner_settings_of_deleted_group() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let member = sandbox.dev_create_account().await?;
+    add_profile(&contract, &member, "member").await?;
+    join_group(&contract, &member, group_id).await?;
+    let outcome = member
+        .call(contract.id(), "set_succession_policy")
+        .args_json(json!({ "group_id": group_id, "policy": "HighestRole" }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure(), "Only the owner sets the policy");
+
+    let outcome = owner
+        .call(contract.id(), "set_succession_policy")
+        .args_json(json!({ "group_id": group_id, "policy": "HighestRole" }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    let _ = owner
+        .call(contract.id(), "delete_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    for (method, args) in [
+        (
+            "set_succession_policy",
+            json!({ "group_id": group_id, "policy": "Disabled" }),
+        ),
+        (
+            "set_group_join_fee",
+            json!({ "group_id": group_id, "join_fee": "1" }),
+        ),
+        (
+            "set_group_membership_duration",
+            json!({ "group_id": group_id, "duration": 1 }),
+        ),
+    ] {
+        let outcome = owner
+            .call(contract.id(), method)
+            .args_json(args)
+            .transact()
+            .await?;
+        assert!(
+            outcome.is_failure(),
+            "{method} should refuse deleted groups"
+        );
+    }
+
+    Ok(())
+}
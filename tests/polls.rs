@@ -0,0 +1,152 @@
+mod common;
+
+use cat_near_contract::models::polls::PollResponse;
+use common::{add_group, add_profile, init};
+use near_sdk::serde_json::json;
+use near_workspaces::{Account, Contract};
+
+async fn vote(
+    contract: &Contract,
+    voter: &Account,
+    poll_id: u64,
+    options: Vec<u32>,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    let outcome = voter
+        .call(contract.id(), "vote_in_poll")
+        .args_json(json!({ "poll_id": poll_id, "options": options }))
+        .transact()
+        .await?;
+    Ok(outcome.is_success())
+}
+
+#[tokio::test]
+async fn test_poll_hides_results_until_deadline() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let member = sandbox.dev_create_account().await?;
+    add_profile(&contract, &member, "member").await?;
+    let outcome = member
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+    let outsider = sandbox.dev_create_account().await?;
+    add_profile(&contract, &outsider, "outsider").await?;
+
+    let now = sandbox.view_block().await?.timestamp();
+    let post_poll = json!({
+        "question": "Where do we meet next?",
+        "options": ["Online", "Berlin", "Lisbon"],
+        "results_visibility": "AfterDeadline",
+        "ends_on": now + 60_000_000_000
+    });
+    let outcome = member
+        .call(contract.id(), "create_poll")
+        .args_json(json!({ "group_id": group_id, "post_poll": post_poll }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure(), "Only admins start polls");
+
+    let poll_id: u64 = owner
+        .call(contract.id(), "create_poll")
+        .args_json(json!({ "group_id": group_id, "post_poll": post_poll }))
+        .transact()
+        .await?
+        .json()?;
+
+    assert!(!vote(&contract, &outsider, poll_id, vec![0]).await?);
+    assert!(
+        !vote(&contract, &member, poll_id, vec![0, 1]).await?,
+        "Single selection"
+    );
+    assert!(!vote(&contract, &member, poll_id, vec![3]).await?);
+    assert!(vote(&contract, &member, poll_id, vec![1]).await?);
+    assert!(
+        !vote(&contract, &member, poll_id, vec![2]).await?,
+        "Members vote once"
+    );
+
+    let has_voted: bool = contract
+        .view("has_voted_in_poll")
+        .args_json(json!({ "poll_id": poll_id, "account_id": member.id() }))
+        .await?
+        .json()?;
+    assert!(has_voted);
+
+    let poll: PollResponse = contract
+        .view("get_poll")
+        .args_json(json!({ "poll_id": poll_id }))
+        .await?
+        .json()?;
+    assert_eq!(poll.voters_count, 1);
+    assert_eq!(poll.results, None);
+
+    sandbox.fast_forward(200).await?;
+
+    assert!(
+        !vote(&contract, &owner, poll_id, vec![0]).await?,
+        "Poll is closed"
+    );
+    let poll: PollResponse = contract
+        .view("get_poll")
+        .args_json(json!({ "poll_id": poll_id }))
+        .await?
+        .json()?;
+    assert!(!poll.is_open);
+    assert_eq!(poll.results, Some(vec![0, 1, 0]));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_banned_members_do_not_vote_in_polls() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let member = sandbox.dev_create_account().await?;
+    add_profile(&contract, &member, "member").await?;
+    let _ = member
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    let outcome = owner
+        .call(contract.id(), "set_group_member_role")
+        .args_json(json!({ "group_id": group_id, "account_id": member.id(), "role": "Banned" }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    let now = sandbox.view_block().await?.timestamp();
+    let poll_id: u64 = owner
+        .call(contract.id(), "create_poll")
+        .args_json(json!({
+            "group_id": group_id,
+            "post_poll": {
+                "question": "Pizza or pasta?",
+                "options": ["Pizza", "Pasta"],
+                "ends_on": now + 60_000_000_000
+            }
+        }))
+        .transact()
+        .await?
+        .json()?;
+
+    assert!(!vote(&contract, &member, poll_id, vec![0]).await?);
+    assert!(vote(&contract, &owner, poll_id, vec![1]).await?);
+
+    let polls: Vec<PollResponse> = contract
+        .view("get_group_polls")
+        .args_json(json!({ "group_id": group_id, "index": 0, "limit": 10 }))
+        .await?
+        .json()?;
+    assert_eq!(polls.len(), 1);
+    assert_eq!(polls[0].voters_count, 1);
+    assert_eq!(polls[0].results, Some(vec![0, 1]));
+
+    Ok(())
+}
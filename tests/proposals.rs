@@ -0,0 +1,225 @@
+mod common;
+
+use cat_near_contract::models::{
+    application_role::ApplicationRole,
+    proposals::{ProposalResponse, ProposalStatus, VotingWeight},
+};
+use common::{add_group, add_profile, init};
+use near_sdk::serde_json::{json, Value};
+use near_workspaces::{Account, Contract};
+
+async fn vote(
+    contract: &Contract,
+    voter: &Account,
+    proposal_id: u64,
+    vote: &str,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    let outcome = voter
+        .call(contract.id(), "vote_on_proposal")
+        .args_json(json!({ "proposal_id": proposal_id, "vote": vote }))
+        .transact()
+        .await?;
+    Ok(outcome.is_success())
+}
+
+#[tokio::test]
+async fn test_proposals_execute_when_passed() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let outcome = owner
+        .call(contract.id(), "set_group_governance")
+        .args_json(json!({
+            "group_id": group_id,
+            "settings": {
+                "quorum_percent": 50,
+                "threshold_percent": 50,
+                "voting_period": 60_000_000_000u64,
+                "voting_weight": "OneMemberOneVote"
+            }
+        }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    let member = sandbox.dev_create_account().await?;
+    add_profile(&contract, &member, "member").await?;
+    let outcome = member
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+    let outsider = sandbox.dev_create_account().await?;
+    add_profile(&contract, &outsider, "outsider").await?;
+
+    let update_proposal: u64 = member
+        .call(contract.id(), "create_proposal")
+        .args_json(json!({
+            "group_id": group_id,
+            "post_proposal": {
+                "title": "New description",
+                "description": "Describe what we do today",
+                "action": { "UpdateGroup": { "update": { "description": "Decided together" } } }
+            }
+        }))
+        .transact()
+        .await?
+        .json()?;
+    let role_proposal: u64 = member
+        .call(contract.id(), "create_proposal")
+        .args_json(json!({
+            "group_id": group_id,
+            "post_proposal": {
+                "title": "Promote me",
+                "description": "",
+                "action": { "ChangeRole": { "account_id": member.id(), "role": "Admin" } }
+            }
+        }))
+        .transact()
+        .await?
+        .json()?;
+
+    assert!(!vote(&contract, &outsider, update_proposal, "Yes").await?);
+    assert!(vote(&contract, &owner, update_proposal, "Yes").await?);
+    assert!(vote(&contract, &member, update_proposal, "Yes").await?);
+    assert!(
+        !vote(&contract, &member, update_proposal, "No").await?,
+        "Members vote once"
+    );
+    assert!(vote(&contract, &owner, role_proposal, "No").await?);
+
+    let outcome = owner
+        .call(contract.id(), "finalize_proposal")
+        .args_json(json!({ "proposal_id": update_proposal }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure(), "Voting is still open");
+
+    sandbox.fast_forward(200).await?;
+
+    let status: String = outsider
+        .call(contract.id(), "finalize_proposal")
+        .args_json(json!({ "proposal_id": update_proposal }))
+        .transact()
+        .await?
+        .json()?;
+    assert_eq!(status, "Executed");
+
+    let group: Value = contract
+        .view("get_group")
+        .args_json(json!({ "id": group_id }))
+        .await?
+        .json()?;
+    assert_eq!(group["description"], "Decided together");
+
+    let status: String = outsider
+        .call(contract.id(), "finalize_proposal")
+        .args_json(json!({ "proposal_id": role_proposal }))
+        .transact()
+        .await?
+        .json()?;
+    assert_eq!(status, "Rejected");
+
+    let proposals: Vec<ProposalResponse> = contract
+        .view("get_group_proposals")
+        .args_json(json!({ "group_id": group_id, "index": 0, "limit": 10 }))
+        .await?
+        .json()?;
+    assert_eq!(proposals.len(), 2);
+    assert_eq!(proposals[0].tally.yes, 2);
+    assert_eq!(proposals[1].status, ProposalStatus::Rejected);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_only_eligible_members_vote() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let member = sandbox.dev_create_account().await?;
+    add_profile(&contract, &member, "member").await?;
+    let _ = member
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    let guest = sandbox.dev_create_account().await?;
+    add_profile(&contract, &guest, "guest").await?;
+    let _ = guest
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    let outcome = owner
+        .call(contract.id(), "set_group_member_role")
+        .args_json(json!({ "group_id": group_id, "account_id": guest.id(), "role": "Guest" }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    // Guests have no say, so they can't propose either
+    let outcome = guest
+        .call(contract.id(), "create_proposal")
+        .args_json(json!({
+            "group_id": group_id,
+            "post_proposal": { "title": "Meet up", "description": "", "action": "Text" }
+        }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure(), "Guests have no voting weight");
+
+    let proposal_id: u64 = owner
+        .call(contract.id(), "create_proposal")
+        .args_json(json!({
+            "group_id": group_id,
+            "post_proposal": { "title": "Meet up", "description": "", "action": "Text" }
+        }))
+        .transact()
+        .await?
+        .json()?;
+
+    // Joining after the proposal was created doesn't give a say in it
+    let late = sandbox.dev_create_account().await?;
+    add_profile(&contract, &late, "late").await?;
+    let _ = late
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(!vote(&contract, &late, proposal_id, "Yes").await?);
+
+    assert!(
+        !vote(&contract, &guest, proposal_id, "Yes").await?,
+        "Guests don't vote, even with one vote per member"
+    );
+    assert!(vote(&contract, &member, proposal_id, "Yes").await?);
+
+    let proposal: Option<ProposalResponse> = contract
+        .view("get_proposal")
+        .args_json(json!({ "proposal_id": proposal_id }))
+        .await?
+        .json()?;
+    let proposal = proposal.unwrap();
+    assert_eq!(proposal.total_weight, 2, "Only the owner and member count");
+    assert_eq!(proposal.tally.yes, 1);
+    assert_eq!(proposal.voters_count, 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_guests_and_banned_have_no_voting_weight() {
+    let by_role = VotingWeight::ByRole {
+        weights: [(ApplicationRole::Guest, 3), (ApplicationRole::Admin, 2)].into(),
+    };
+    for voting_weight in [VotingWeight::OneMemberOneVote, by_role.clone()] {
+        assert_eq!(voting_weight.of(&ApplicationRole::Guest), 0);
+        assert_eq!(voting_weight.of(&ApplicationRole::Banned), 0);
+        assert_eq!(voting_weight.of(&ApplicationRole::Member), 1);
+    }
+    assert_eq!(by_role.of(&ApplicationRole::Admin), 2);
+}
@@ -0,0 +1,77 @@
+mod common;
+
+use cat_near_contract::models::groups::GroupResponse;
+use common::{add_profile, init};
+use near_sdk::serde_json::json;
+use near_workspaces::{Account, Contract};
+
+async fn add_described_group(
+    contract: &Contract,
+    owner: &Account,
+    name: &str,
+    description: &str,
+) -> Result<u32, Box<dyn std::error::Error>> {
+    let group_id = owner
+        .call(contract.id(), "add_group")
+        .args_json(json!({
+            "post_group": {
+                "name": name,
+                "description": description,
+                "website": "https://example.com",
+                "image": "ipfs://example",
+                "banner_image": "ipfs://example_banner",
+                "matrix_space_id": "space123",
+                "tags": vec![1, 2, 3]
+            }
+        }))
+        .transact()
+        .await?
+        .json::<u32>()?;
+    Ok(group_id)
+}
+
+async fn search(contract: &Contract, query: &str) -> Result<Vec<u32>, Box<dyn std::error::Error>> {
+    let groups: Vec<GroupResponse> = contract
+        .view("search_groups")
+        .args_json(json!({ "query": query, "index": 0, "limit": 10 }))
+        .await?
+        .json()?;
+    Ok(groups.into_iter().map(|group| group.id).collect())
+}
+
+#[tokio::test]
+async fn test_search_groups() -> Result<(), Box<dyn std::error::Error>> {
+    let (_, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+
+    let rust =
+        add_described_group(&contract, &owner, "Rustaceans Berlin", "Meetups about Rust").await?;
+    let near =
+        add_described_group(&contract, &owner, "NEAR Builders", "Rust smart contracts").await?;
+
+    assert_eq!(search(&contract, "rus").await?, vec![rust]);
+    assert_eq!(search(&contract, "rust").await?, vec![rust, near]);
+    assert_eq!(search(&contract, "rust contracts").await?, vec![near]);
+    assert_eq!(search(&contract, "BERL").await?, vec![rust]);
+    assert!(search(&contract, "python").await?.is_empty());
+
+    // Renamed and deleted groups leave the results
+    let outcome = owner
+        .call(contract.id(), "edit_group")
+        .args_json(json!({ "id": near, "update_group": { "name": "Chain Builders" } }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+    assert!(search(&contract, "near").await?.is_empty());
+    assert_eq!(search(&contract, "chain").await?, vec![near]);
+
+    let outcome = owner
+        .call(contract.id(), "delete_group")
+        .args_json(json!({ "group_id": rust }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+    assert_eq!(search(&contract, "rust").await?, vec![near]);
+
+    Ok(())
+}
@@ -0,0 +1,171 @@
+mod common;
+
+use cat_near_contract::models::groups::GroupResponse;
+use common::{add_group, add_profile, init};
+use near_sdk::serde_json::json;
+use near_workspaces::{Account, Contract};
+
+async fn add_subgroup(
+    contract: &Contract,
+    creator: &Account,
+    parent_id: u32,
+    name: &str,
+) -> Result<near_workspaces::result::ExecutionFinalResult, Box<dyn std::error::Error>> {
+    Ok(creator
+        .call(contract.id(), "add_group")
+        .args_json(json!({
+            "post_group": {
+                "name": name,
+                "description": "A sub-group",
+                "website": "https://example.com",
+                "image": "ipfs://example",
+                "banner_image": "ipfs://example_banner",
+                "matrix_space_id": "space123",
+                "tags": vec![1, 2, 3],
+                "parent_id": parent_id,
+                "requires_parent_membership": true
+            }
+        }))
+        .transact()
+        .await?)
+}
+
+#[tokio::test]
+async fn test_subgroup_creation_and_views() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let parent_id = add_group(&contract, &owner).await?;
+
+    let member = sandbox.dev_create_account().await?;
+    add_profile(&contract, &member, "member").await?;
+    let _ = member
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": parent_id }))
+        .transact()
+        .await?;
+
+    // Plain members of the parent can't create sub-groups
+    let outcome = add_subgroup(&contract, &member, parent_id, "Member Chapter").await?;
+    assert!(outcome.is_failure());
+
+    let child_id: u32 = add_subgroup(&contract, &owner, parent_id, "Berlin Chapter")
+        .await?
+        .json()?;
+    let grandchild_id: u32 = add_subgroup(&contract, &owner, child_id, "Berlin Working Group")
+        .await?
+        .json()?;
+
+    let children: Vec<GroupResponse> = contract
+        .view("get_group_children")
+        .args_json(json!({ "group_id": parent_id }))
+        .await?
+        .json()?;
+    let child_ids: Vec<u32> = children.iter().map(|group| group.id).collect();
+    assert_eq!(child_ids, vec![child_id]);
+
+    let ancestry: Vec<GroupResponse> = contract
+        .view("get_group_ancestry")
+        .args_json(json!({ "group_id": grandchild_id }))
+        .await?
+        .json()?;
+    let ancestry_ids: Vec<u32> = ancestry.iter().map(|group| group.id).collect();
+    assert_eq!(ancestry_ids, vec![parent_id, child_id, grandchild_id]);
+
+    // Deleted ancestors are only listed on request
+    let _ = owner
+        .call(contract.id(), "delete_group")
+        .args_json(json!({ "group_id": child_id }))
+        .transact()
+        .await?;
+    let ancestry: Vec<GroupResponse> = contract
+        .view("get_group_ancestry")
+        .args_json(json!({ "group_id": grandchild_id }))
+        .await?
+        .json()?;
+    let ancestry_ids: Vec<u32> = ancestry.iter().map(|group| group.id).collect();
+    assert_eq!(ancestry_ids, vec![parent_id, grandchild_id]);
+
+    let ancestry: Vec<GroupResponse> = contract
+        .view("get_group_ancestry")
+        .args_json(json!({ "group_id": grandchild_id, "include_deleted": true }))
+        .await?
+        .json()?;
+    assert_eq!(ancestry.len(), 3);
+
+    // Creating sub-groups is a permission like any other
+    let _ = owner
+        .call(contract.id(), "set_group_role_permissions")
+        .args_json(json!({
+            "group_id": parent_id,
+            "role": "Member",
+            "permissions": ["CreateSubgroups"]
+        }))
+        .transact()
+        .await?;
+    let outcome = add_subgroup(&contract, &member, parent_id, "Member Chapter").await?;
+    assert!(outcome.is_success());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_subgroup_requires_parent_membership() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let parent_id = add_group(&contract, &owner).await?;
+    let child_id: u32 = add_subgroup(&contract, &owner, parent_id, "Berlin Chapter")
+        .await?
+        .json()?;
+
+    let user = sandbox.dev_create_account().await?;
+    add_profile(&contract, &user, "user").await?;
+
+    let outcome = user
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": child_id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure());
+
+    let _ = user
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": parent_id }))
+        .transact()
+        .await?;
+    let outcome = user
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": child_id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    // Holding the Banned role in the parent is not membership
+    let banned = sandbox.dev_create_account().await?;
+    add_profile(&contract, &banned, "banned").await?;
+    let _ = banned
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": parent_id }))
+        .transact()
+        .await?;
+    let outcome = owner
+        .call(contract.id(), "set_group_member_role")
+        .args_json(json!({
+            "group_id": parent_id,
+            "account_id": banned.id(),
+            "role": "Banned"
+        }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+    let outcome = banned
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": child_id }))
+        .transact()
+        .await?;
+    assert!(
+        outcome.is_failure(),
+        "Banned parent members should not join"
+    );
+
+    Ok(())
+}
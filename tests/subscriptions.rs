@@ -0,0 +1,212 @@
+mod common;
+
+use cat_near_contract::models::{
+    groups::GroupResponse, members::MemberResponse, sbt::OwnedSbtToken,
+};
+use common::{add_group, add_profile, init};
+use near_sdk::serde_json::json;
+use near_sdk::AccountId;
+
+#[tokio::test]
+async fn test_membership_expires_and_renews() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let one_minute: u64 = 60 * 1_000_000_000;
+    let outcome = owner
+        .call(contract.id(), "set_group_membership_duration")
+        .args_json(json!({ "group_id": group_id, "duration": one_minute }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    let member = sandbox.dev_create_account().await?;
+    add_profile(&contract, &member, "member").await?;
+    let outcome = member
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    let in_group: bool = contract
+        .view("is_user_in_group")
+        .args_json(json!({ "account_id": member.id(), "group_id": group_id }))
+        .await?
+        .json()?;
+    assert!(in_group);
+
+    let expiring: Vec<MemberResponse> = contract
+        .view("get_upcoming_expirations")
+        .args_json(
+            json!({ "group_id": group_id, "within": 2 * one_minute, "index": 0, "limit": 10 }),
+        )
+        .await?
+        .json()?;
+    assert_eq!(expiring.len(), 1, "Only the member expires, not the owner");
+    assert_eq!(expiring[0].account_id, *member.id());
+
+    sandbox.fast_forward(200).await?;
+
+    let in_group: bool = contract
+        .view("is_user_in_group")
+        .args_json(json!({ "account_id": member.id(), "group_id": group_id }))
+        .await?
+        .json()?;
+    assert!(!in_group, "Expired members are no longer in the group");
+
+    let role: Option<String> = contract
+        .view("get_user_role_in_group")
+        .args_json(json!({ "account_id": member.id(), "group_id": group_id }))
+        .await?
+        .json()?;
+    assert_eq!(role, None);
+
+    let outcome = member
+        .call(contract.id(), "renew_membership")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    let in_group: bool = contract
+        .view("is_user_in_group")
+        .args_json(json!({ "account_id": member.id(), "group_id": group_id }))
+        .await?
+        .json()?;
+    assert!(in_group);
+
+    // The owner's membership never expires, so there is nothing to renew
+    let outcome = owner
+        .call(contract.id(), "renew_membership")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_expired_members_free_their_seat() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let one_minute: u64 = 60 * 1_000_000_000;
+    let _ = owner
+        .call(contract.id(), "set_group_membership_duration")
+        .args_json(json!({ "group_id": group_id, "duration": one_minute }))
+        .transact()
+        .await?;
+    let _ = owner
+        .call(contract.id(), "edit_group")
+        .args_json(json!({
+            "id": group_id,
+            "update_group": { "max_members": 2, "mint_membership_tokens": true }
+        }))
+        .transact()
+        .await?;
+
+    let member = sandbox.dev_create_account().await?;
+    add_profile(&contract, &member, "member").await?;
+    let outcome = member
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    // The token expires with the membership, in milliseconds
+    let expires_on: Option<u64> = contract
+        .view("get_membership_expiry")
+        .args_json(json!({ "group_id": group_id, "account_id": member.id() }))
+        .await?
+        .json()?;
+    let tokens: Vec<(AccountId, Vec<OwnedSbtToken>)> = contract
+        .view("sbt_tokens_by_owner")
+        .args_json(json!({ "account": member.id() }))
+        .await?
+        .json()?;
+    assert_eq!(
+        tokens[0].1[0].metadata.expires_at,
+        expires_on.map(|expires_on| expires_on / 1_000_000)
+    );
+
+    sandbox.fast_forward(200).await?;
+
+    let group: Option<GroupResponse> = contract
+        .view("get_group")
+        .args_json(json!({ "id": group_id }))
+        .await?
+        .json()?;
+    assert_eq!(
+        group.unwrap().members_count,
+        1,
+        "Expired members don't count"
+    );
+
+    let tokens: Vec<(AccountId, Vec<OwnedSbtToken>)> = contract
+        .view("sbt_tokens_by_owner")
+        .args_json(json!({ "account": member.id() }))
+        .await?
+        .json()?;
+    assert!(tokens.is_empty(), "Expired tokens are left out");
+
+    // The expired member doesn't hold a seat
+    let newcomer = sandbox.dev_create_account().await?;
+    add_profile(&contract, &newcomer, "newcomer").await?;
+    let outcome = newcomer
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+    let in_group: bool = contract
+        .view("is_user_in_group")
+        .args_json(json!({ "account_id": newcomer.id(), "group_id": group_id }))
+        .await?
+        .json()?;
+    assert!(in_group, "Newcomer should not be waitlisted");
+
+    // Nor can it take the seat back while the group is full
+    let outcome = member
+        .call(contract.id(), "renew_membership")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(
+        outcome.is_failure(),
+        "Renewing should not overfill the group"
+    );
+
+    // Renewing into a free seat moves the token expiry along
+    let _ = owner
+        .call(contract.id(), "set_group_max_members")
+        .args_json(json!({ "group_id": group_id, "max_members": 3 }))
+        .transact()
+        .await?;
+    let outcome = member
+        .call(contract.id(), "renew_membership")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+    let expires_on: Option<u64> = contract
+        .view("get_membership_expiry")
+        .args_json(json!({ "group_id": group_id, "account_id": member.id() }))
+        .await?
+        .json()?;
+    let tokens: Vec<(AccountId, Vec<OwnedSbtToken>)> = contract
+        .view("sbt_tokens_by_owner")
+        .args_json(json!({ "account": member.id() }))
+        .await?
+        .json()?;
+    assert_eq!(
+        tokens[0].1[0].metadata.expires_at,
+        expires_on.map(|expires_on| expires_on / 1_000_000)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,109 @@
+mod common;
+
+use cat_near_contract::models::groups::GroupResponse;
+use common::{add_profile, init};
+use near_sdk::serde_json::json;
+use near_workspaces::{Account, Contract};
+
+async fn add_tagged_group(
+    contract: &Contract,
+    owner: &Account,
+    name: &str,
+    tags: Vec<u32>,
+) -> Result<u32, Box<dyn std::error::Error>> {
+    let group_id = owner
+        .call(contract.id(), "add_group")
+        .args_json(json!({
+            "post_group": {
+                "name": name,
+                "description": "A test group",
+                "website": "https://example.com",
+                "image": "ipfs://example",
+                "banner_image": "ipfs://example_banner",
+                "matrix_space_id": "space123",
+                "tags": tags
+            }
+        }))
+        .transact()
+        .await?
+        .json::<u32>()?;
+    Ok(group_id)
+}
+
+async fn get_groups_by_tag(
+    contract: &Contract,
+    tags: Vec<u32>,
+    match_all: bool,
+) -> Result<Vec<u32>, Box<dyn std::error::Error>> {
+    let groups: Vec<GroupResponse> = contract
+        .view("get_groups_by_tag")
+        .args_json(json!({ "tags": tags, "match_all": match_all, "index": 0, "limit": 10 }))
+        .await?
+        .json()?;
+    Ok(groups.into_iter().map(|group| group.id).collect())
+}
+
+#[tokio::test]
+async fn test_get_groups_by_tag() -> Result<(), Box<dyn std::error::Error>> {
+    let (_, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+
+    let first = add_tagged_group(&contract, &owner, "First Group", vec![1, 2]).await?;
+    let second = add_tagged_group(&contract, &owner, "Second Group", vec![2, 3]).await?;
+
+    let group: GroupResponse = contract
+        .view("get_group")
+        .args_json(json!({ "id": first }))
+        .await?
+        .json()?;
+    assert_eq!(group.tags, vec![1, 2]);
+
+    assert_eq!(
+        get_groups_by_tag(&contract, vec![2], false).await?,
+        vec![first, second]
+    );
+    assert_eq!(
+        get_groups_by_tag(&contract, vec![1, 3], false).await?,
+        vec![first, second]
+    );
+    assert_eq!(
+        get_groups_by_tag(&contract, vec![1, 2], true).await?,
+        vec![first]
+    );
+    assert!(get_groups_by_tag(&contract, vec![1, 3], true)
+        .await?
+        .is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_edit_group_tags() -> Result<(), Box<dyn std::error::Error>> {
+    let (_, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_tagged_group(&contract, &owner, "Tagged Group", vec![1, 2]).await?;
+
+    let outcome = owner
+        .call(contract.id(), "edit_group")
+        .args_json(json!({ "id": group_id, "update_group": { "tags": [2, 4] } }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    assert!(get_groups_by_tag(&contract, vec![1], false)
+        .await?
+        .is_empty());
+    assert_eq!(
+        get_groups_by_tag(&contract, vec![4], false).await?,
+        vec![group_id]
+    );
+
+    let count: u32 = contract
+        .view("get_tag_group_count")
+        .args_json(json!({ "tag": 1 }))
+        .await?
+        .json()?;
+    assert_eq!(count, 0);
+
+    Ok(())
+}
@@ -0,0 +1,158 @@
+mod common;
+
+use common::{add_group, add_profile, init};
+use near_sdk::serde_json::json;
+use near_workspaces::{network::Sandbox, Account, Contract, Worker};
+
+async fn deploy_mock_ft(sandbox: &Worker<Sandbox>) -> Result<Contract, Box<dyn std::error::Error>> {
+    let wasm = near_workspaces::compile_project("./tests/contracts/mock_ft").await?;
+    Ok(sandbox.dev_deploy(&wasm).await?)
+}
+
+async fn set_balance(
+    token: &Contract,
+    account: &Account,
+    balance: u128,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let outcome = token
+        .call("set_balance")
+        .args_json(json!({ "account_id": account.id(), "balance": balance.to_string() }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+    Ok(())
+}
+
+async fn is_member(
+    contract: &Contract,
+    group_id: u32,
+    account: &Account,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    Ok(contract
+        .view("is_user_in_group")
+        .args_json(json!({ "account_id": account.id(), "group_id": group_id }))
+        .await?
+        .json()?)
+}
+
+#[tokio::test]
+async fn test_token_gated_join() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    let token = deploy_mock_ft(&sandbox).await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let outcome = owner
+        .call(contract.id(), "set_group_gate")
+        .args_json(json!({
+            "group_id": group_id,
+            "gate": { "FungibleToken": { "contract_id": token.id(), "min_balance": "100" } }
+        }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    let poor = sandbox.dev_create_account().await?;
+    add_profile(&contract, &poor, "poor").await?;
+    set_balance(&token, &poor, 99).await?;
+
+    let outcome = poor
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .max_gas()
+        .transact()
+        .await?;
+    assert!(outcome.is_failure());
+    assert!(!is_member(&contract, group_id, &poor).await?);
+
+    let holder = sandbox.dev_create_account().await?;
+    add_profile(&contract, &holder, "holder").await?;
+    set_balance(&token, &holder, 100).await?;
+
+    let outcome = holder
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .max_gas()
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+    assert!(is_member(&contract, group_id, &holder).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_group_gate_owner_only() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    let token = deploy_mock_ft(&sandbox).await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let member = sandbox.dev_create_account().await?;
+    add_profile(&contract, &member, "member").await?;
+    let _ = member
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+
+    let outcome = member
+        .call(contract.id(), "set_group_gate")
+        .args_json(json!({
+            "group_id": group_id,
+            "gate": { "FungibleToken": { "contract_id": token.id(), "min_balance": "1" } }
+        }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_nft_gated_join() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    let nft_wasm = near_workspaces::compile_project("./tests/contracts/mock_nft").await?;
+    let collection = sandbox.dev_deploy(&nft_wasm).await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let outcome = owner
+        .call(contract.id(), "set_group_gate")
+        .args_json(json!({
+            "group_id": group_id,
+            "gate": { "NonFungibleToken": { "contract_id": collection.id() } }
+        }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    let holder = sandbox.dev_create_account().await?;
+    add_profile(&contract, &holder, "holder").await?;
+
+    let outcome = holder
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .max_gas()
+        .transact()
+        .await?;
+    assert!(outcome.is_failure());
+
+    let outcome = collection
+        .call("nft_mint")
+        .args_json(json!({ "token_id": "1", "owner_id": holder.id() }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    let outcome = holder
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .max_gas()
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+    assert!(is_member(&contract, group_id, &holder).await?);
+
+    Ok(())
+}
@@ -0,0 +1,244 @@
+mod common;
+
+use cat_near_contract::models::treasury::{TreasuryTransfer, TreasuryTransferKind};
+use common::{add_group, add_profile, init};
+use near_sdk::serde_json::json;
+use near_workspaces::types::NearToken;
+
+#[tokio::test]
+async fn test_paid_membership_and_treasury() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+
+    let outcome = owner
+        .call(contract.id(), "set_group_join_fee")
+        .args_json(json!({ "group_id": group_id, "join_fee": NearToken::from_near(1).as_yoctonear().to_string() }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    let member = sandbox.dev_create_account().await?;
+    add_profile(&contract, &member, "member").await?;
+
+    let outcome = member
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure(), "Joining without the fee should fail");
+
+    // Overpaying gets the difference back
+    let balance_before = member.view_account().await?.balance;
+    let outcome = member
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .deposit(NearToken::from_near(3))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+    let spent = balance_before.saturating_sub(member.view_account().await?.balance);
+    assert!(spent < NearToken::from_millinear(1100));
+
+    let balance: String = contract
+        .view("get_group_treasury_balance")
+        .args_json(json!({ "group_id": group_id }))
+        .await?
+        .json()?;
+    assert_eq!(balance, NearToken::from_near(1).as_yoctonear().to_string());
+
+    let amount = NearToken::from_millinear(400).as_yoctonear().to_string();
+    let outcome = member
+        .call(contract.id(), "withdraw_from_treasury")
+        .args_json(json!({ "group_id": group_id, "amount": amount }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure(), "Members can't withdraw");
+
+    let outcome = owner
+        .call(contract.id(), "set_group_member_role")
+        .args_json(json!({ "group_id": group_id, "account_id": member.id(), "role": "Treasurer" }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    let outcome = member
+        .call(contract.id(), "withdraw_from_treasury")
+        .args_json(json!({ "group_id": group_id, "amount": amount, "receiver_id": owner.id() }))
+        .max_gas()
+        .transact()
+        .await?;
+    assert!(outcome.is_success(), "Treasurers can withdraw");
+
+    let balance: String = contract
+        .view("get_group_treasury_balance")
+        .args_json(json!({ "group_id": group_id }))
+        .await?
+        .json()?;
+    assert_eq!(
+        balance,
+        NearToken::from_millinear(600).as_yoctonear().to_string()
+    );
+
+    let transfers: Vec<TreasuryTransfer> = contract
+        .view("get_group_treasury_transfers")
+        .args_json(json!({ "group_id": group_id, "index": 0, "limit": 10 }))
+        .await?
+        .json()?;
+    assert_eq!(transfers.len(), 2);
+    assert_eq!(transfers[0].kind, TreasuryTransferKind::JoinFee);
+    assert_eq!(transfers[1].kind, TreasuryTransferKind::Withdrawal);
+    assert_eq!(transfers[1].account_id, *owner.id());
+    assert_eq!(transfers[1].initiated_by, *member.id());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_join_fee_with_invite_and_join_request() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+    let join_fee = NearToken::from_near(1);
+
+    let _ = owner
+        .call(contract.id(), "set_group_join_fee")
+        .args_json(json!({ "group_id": group_id, "join_fee": join_fee.as_yoctonear().to_string() }))
+        .transact()
+        .await?;
+
+    // Invites don't skip the fee
+    let code: String = owner
+        .call(contract.id(), "create_group_invite")
+        .args_json(json!({
+            "group_id": group_id,
+            "post_group_invite": { "expires_on": u64::MAX, "max_uses": 10 }
+        }))
+        .transact()
+        .await?
+        .json()?;
+    let invitee = sandbox.dev_create_account().await?;
+    add_profile(&contract, &invitee, "invitee").await?;
+    let outcome = invitee
+        .call(contract.id(), "join_group_with_invite")
+        .args_json(json!({ "code": code }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure(), "Invite without the fee should fail");
+    let outcome = invitee
+        .call(contract.id(), "join_group_with_invite")
+        .args_json(json!({ "code": code }))
+        .deposit(join_fee)
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    // Join requests hold the fee until they are decided
+    let _ = owner
+        .call(contract.id(), "edit_group")
+        .args_json(json!({
+            "id": group_id,
+            "update_group": { "join_policy": "RequestToJoin" }
+        }))
+        .transact()
+        .await?;
+    let applicant = sandbox.dev_create_account().await?;
+    add_profile(&contract, &applicant, "applicant").await?;
+    let outcome = applicant
+        .call(contract.id(), "request_to_join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure(), "Request without the fee should fail");
+    let _ = applicant
+        .call(contract.id(), "request_to_join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .deposit(join_fee)
+        .transact()
+        .await?;
+
+    let rejected = sandbox.dev_create_account().await?;
+    add_profile(&contract, &rejected, "rejected").await?;
+    let _ = rejected
+        .call(contract.id(), "request_to_join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .deposit(join_fee)
+        .transact()
+        .await?;
+
+    let _ = owner
+        .call(contract.id(), "approve_join_requests")
+        .args_json(json!({ "group_id": group_id, "account_ids": [applicant.id()] }))
+        .transact()
+        .await?;
+    let balance_before = rejected.view_account().await?.balance;
+    let _ = owner
+        .call(contract.id(), "reject_join_requests")
+        .args_json(json!({ "group_id": group_id, "account_ids": [rejected.id()] }))
+        .transact()
+        .await?;
+    let refunded = rejected
+        .view_account()
+        .await?
+        .balance
+        .saturating_sub(balance_before);
+    assert_eq!(refunded, join_fee, "Rejected request should be refunded");
+
+    let balance: String = contract
+        .view("get_group_treasury_balance")
+        .args_json(json!({ "group_id": group_id }))
+        .await?
+        .json()?;
+    assert_eq!(
+        balance,
+        NearToken::from_near(2).as_yoctonear().to_string(),
+        "Invitee and approved applicant should have paid"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ban_refunds_waitlisted_payer() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_group(&contract, &owner).await?;
+    let join_fee = NearToken::from_near(1);
+
+    let _ = owner
+        .call(contract.id(), "edit_group")
+        .args_json(json!({ "id": group_id, "update_group": { "max_members": 1 } }))
+        .transact()
+        .await?;
+    let _ = owner
+        .call(contract.id(), "set_group_join_fee")
+        .args_json(json!({ "group_id": group_id, "join_fee": join_fee.as_yoctonear().to_string() }))
+        .transact()
+        .await?;
+
+    let payer = sandbox.dev_create_account().await?;
+    add_profile(&contract, &payer, "payer").await?;
+    let outcome = payer
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .deposit(join_fee)
+        .transact()
+        .await?;
+    assert!(outcome.is_success(), "Payer should be waitlisted");
+
+    let balance_before = payer.view_account().await?.balance;
+    let outcome = owner
+        .call(contract.id(), "ban_group_member")
+        .args_json(json!({ "group_id": group_id, "account_id": payer.id() }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+    let refunded = payer
+        .view_account()
+        .await?
+        .balance
+        .saturating_sub(balance_before);
+    assert_eq!(refunded, join_fee, "Held join fee should be refunded");
+
+    Ok(())
+}
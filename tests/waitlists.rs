@@ -0,0 +1,150 @@
+mod common;
+
+use cat_near_contract::models::rewards::Rewards;
+use common::{add_profile, init};
+use near_sdk::serde_json::json;
+use near_workspaces::{Account, Contract};
+
+async fn add_limited_group(
+    contract: &Contract,
+    owner: &Account,
+    max_members: u32,
+) -> Result<u32, Box<dyn std::error::Error>> {
+    let group_id = owner
+        .call(contract.id(), "add_group")
+        .args_json(json!({
+            "post_group": {
+                "name": "Cohort Group",
+                "description": "A test group",
+                "website": "https://example.com",
+                "image": "ipfs://example",
+                "banner_image": "ipfs://example_banner",
+                "matrix_space_id": "space123",
+                "tags": vec![1, 2, 3],
+                "max_members": max_members
+            }
+        }))
+        .transact()
+        .await?
+        .json::<u32>()?;
+    Ok(group_id)
+}
+
+async fn waitlist_position(
+    contract: &Contract,
+    group_id: u32,
+    account: &Account,
+) -> Result<Option<u32>, Box<dyn std::error::Error>> {
+    Ok(contract
+        .view("get_waitlist_position")
+        .args_json(json!({ "group_id": group_id, "account_id": account.id() }))
+        .await?
+        .json()?)
+}
+
+#[tokio::test]
+async fn test_waitlist_promotion() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_limited_group(&contract, &owner, 2).await?;
+
+    let mut accounts = vec![];
+    for username in ["first", "second", "third"] {
+        let account = sandbox.dev_create_account().await?;
+        add_profile(&contract, &account, username).await?;
+        let outcome = account
+            .call(contract.id(), "join_group")
+            .args_json(json!({ "group_id": group_id }))
+            .transact()
+            .await?;
+        assert!(outcome.is_success());
+        accounts.push(account);
+    }
+    let [first, second, third] = &accounts[..] else {
+        unreachable!()
+    };
+
+    assert_eq!(waitlist_position(&contract, group_id, first).await?, None);
+    assert_eq!(
+        waitlist_position(&contract, group_id, second).await?,
+        Some(1)
+    );
+    assert_eq!(
+        waitlist_position(&contract, group_id, third).await?,
+        Some(2)
+    );
+
+    // A free seat goes to the next in line, who gets the join reward
+    let outcome = first
+        .call(contract.id(), "leave_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    let is_member: bool = contract
+        .view("is_user_in_group")
+        .args_json(json!({ "account_id": second.id(), "group_id": group_id }))
+        .await?
+        .json()?;
+    assert!(is_member);
+    assert_eq!(
+        waitlist_position(&contract, group_id, third).await?,
+        Some(1)
+    );
+
+    let rewards: Rewards = contract
+        .view("get_rewards")
+        .args_json(json!({ "account_id": second.id() }))
+        .await?
+        .json()?;
+    assert!(rewards
+        .actions
+        .group_join_action_history
+        .contains(&group_id));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_raise_max_members() -> Result<(), Box<dyn std::error::Error>> {
+    let (sandbox, contract, owner) = init().await?;
+    add_profile(&contract, &owner, "owner").await?;
+    let group_id = add_limited_group(&contract, &owner, 1).await?;
+
+    let member = sandbox.dev_create_account().await?;
+    add_profile(&contract, &member, "member").await?;
+    let _ = member
+        .call(contract.id(), "join_group")
+        .args_json(json!({ "group_id": group_id }))
+        .transact()
+        .await?;
+    assert_eq!(
+        waitlist_position(&contract, group_id, &member).await?,
+        Some(1)
+    );
+
+    let outcome = member
+        .call(contract.id(), "set_group_max_members")
+        .args_json(json!({ "group_id": group_id, "max_members": 2 }))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure());
+
+    let outcome = owner
+        .call(contract.id(), "set_group_max_members")
+        .args_json(json!({ "group_id": group_id, "max_members": 2 }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    let is_member: bool = contract
+        .view("is_user_in_group")
+        .args_json(json!({ "account_id": member.id(), "group_id": group_id }))
+        .await?
+        .json()?;
+    assert!(is_member);
+    assert_eq!(waitlist_position(&contract, group_id, &member).await?, None);
+
+    Ok(())
+}